@@ -0,0 +1,60 @@
+//! Implements the access endpoint
+//!
+//! Additional the access rights of a single project are retrievable via
+//! `/projects/{project-name}/access`.
+
+use call;
+use error::GGRError;
+use error::GGRResult;
+use error::GerritError;
+use entities;
+use std::collections::HashMap;
+use url;
+
+const ENDPOINT: &'static str = "/a/access";
+const PROJECTS_ENDPOINT: &'static str = "/a/projects";
+
+/// Interface to retrieve access rights from gerrit server
+pub struct Access {
+    call: call::Call,
+}
+
+impl Access {
+    /// create a new `Access` instance
+    pub fn new(url: &url::Url) -> Access {
+        Access {
+            call: call::Call::new(url),
+        }
+    }
+
+    /// api function 'GET /access/?project={project-name}'
+    ///
+    /// returns a map of project names to `ProjectAccessInfo` entities. Only the local access
+    /// rights of the projects are returned, inherited rights are found via `inherits_from`.
+    pub fn list_access_rights(&mut self, projects: Vec<&str>) -> GGRResult<HashMap<String, entities::ProjectAccessInfo>> {
+        if projects.is_empty() || projects.iter().any(|p| p.is_empty()) {
+            return Err(GGRError::GerritApiError(GerritError::ProjectNameEmpty));
+        }
+
+        let querystring = projects.iter()
+            .map(|p| format!("project={}", call::encode_query_value(p)))
+            .collect::<Vec<String>>()
+            .join("&");
+        self.call.set_url_query(Some(&querystring));
+
+        let path = format!("{}/", ENDPOINT);
+
+        self.call.execute::<(),HashMap<String, entities::ProjectAccessInfo>>("list access rights", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /projects/{project-name}/access'
+    pub fn get_access_rights(&self, project: &str) -> GGRResult<entities::ProjectAccessInfo> {
+        if project.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ProjectNameEmpty));
+        }
+
+        let path = format!("{}/{}/access", PROJECTS_ENDPOINT, call::encode_path_segment(project));
+
+        self.call.execute::<(),entities::ProjectAccessInfo>("get access rights", &path, call::CallMethod::Get, None)
+    }
+}
@@ -0,0 +1,329 @@
+
+//! Implements the accounts endpoint
+//!
+//! The `{account-id}` can be the numeric account id, the username, an email address, a full name
+//! or the string `self` for the calling user.
+
+use call;
+use error::GGRError;
+use error::GGRResult;
+use error::GerritError;
+use entities;
+use std::collections::HashMap;
+use url;
+
+const ENDPOINT: &'static str = "/a/accounts";
+
+/// Interface to retrieve Accounts information from gerrit server
+pub struct Accounts {
+    call: call::Call,
+}
+
+impl Accounts {
+    /// create a new `Accounts` instance
+    pub fn new(url: &url::Url) -> Accounts {
+        Accounts {
+            call: call::Call::new(url),
+        }
+    }
+
+    /// check `accountid` and returns the endpoint path for this account
+    fn account_path(accountid: &str) -> GGRResult<String> {
+        if accountid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::AccountIDEmpty));
+        }
+
+        Ok(format!("{}/{}", ENDPOINT, call::encode_path_segment(accountid)))
+    }
+
+    /// api function 'GET /accounts/'
+    ///
+    /// `options` are additional fields like `DETAILS` or `ALL_EMAILS`, `limit` restricts the count
+    /// of returned accounts.
+    pub fn query_accounts(&mut self, query: &str, options: Option<Vec<&str>>, limit: Option<usize>) -> GGRResult<Vec<entities::AccountInfo>> {
+        let mut querystring = format!("q={}", call::encode_query_value(query));
+        if let Some(options) = options {
+            for option in options {
+                querystring = format!("{}&o={}", querystring, option);
+            }
+        }
+        if let Some(limit) = limit {
+            querystring = format!("{}&n={}", querystring, limit);
+        }
+
+        self.call.set_url_query(Some(&querystring));
+
+        let path = format!("{}/", ENDPOINT);
+
+        self.call.execute::<(),Vec<entities::AccountInfo>>("query accounts", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}'
+    pub fn get_account(&self, accountid: &str) -> GGRResult<entities::AccountInfo> {
+        let path = Accounts::account_path(accountid)?;
+
+        self.call.execute::<(),entities::AccountInfo>("get account", &path, call::CallMethod::Get, None)
+    }
+
+    /// convenience function for 'GET /accounts/self'
+    ///
+    /// returns the account of the calling user
+    pub fn get_self(&self) -> GGRResult<entities::AccountInfo> {
+        self.get_account("self")
+    }
+
+    /// api function 'GET /accounts/{account-id}/name'
+    ///
+    /// returns `None` if the account has no name
+    pub fn get_name(&self, accountid: &str) -> GGRResult<Option<String>> {
+        let path = format!("{}/name", Accounts::account_path(accountid)?);
+
+        self.call.execute::<(),Option<String>>("get account name", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /accounts/{account-id}/name'
+    ///
+    /// A `None` or empty `name` deletes the name of the account.
+    pub fn set_name(&self, accountid: &str, name: Option<&str>) -> GGRResult<Option<String>> {
+        let path = format!("{}/name", Accounts::account_path(accountid)?);
+
+        let nameinput = entities::AccountNameInput {
+            name: name.map(|s| s.to_string()),
+        };
+
+        self.call.execute::<&entities::AccountNameInput,Option<String>>("set account name", &path, call::CallMethod::Put, Some(&&nameinput))
+    }
+
+    /// api function 'DELETE /accounts/{account-id}/name'
+    pub fn delete_name(&self, accountid: &str) -> GGRResult<()> {
+        let path = format!("{}/name", Accounts::account_path(accountid)?);
+
+        self.call.execute::<(),()>("delete account name", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/username'
+    pub fn get_username(&self, accountid: &str) -> GGRResult<String> {
+        let path = format!("{}/username", Accounts::account_path(accountid)?);
+
+        self.call.execute::<(),String>("get username", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /accounts/{account-id}/username'
+    ///
+    /// The username can only be set once.
+    pub fn set_username(&self, accountid: &str, username: &str) -> GGRResult<String> {
+        let path = format!("{}/username", Accounts::account_path(accountid)?);
+
+        let usernameinput = entities::UsernameInput {
+            username: username.into(),
+        };
+
+        self.call.execute::<&entities::UsernameInput,String>("set username", &path, call::CallMethod::Put, Some(&&usernameinput))
+    }
+
+    /// api function 'GET /accounts/{account-id}/emails'
+    pub fn list_emails(&self, accountid: &str) -> GGRResult<Vec<entities::EmailInfo>> {
+        let path = format!("{}/emails", Accounts::account_path(accountid)?);
+
+        self.call.execute::<(),Vec<entities::EmailInfo>>("list account emails", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/emails/{email-id}'
+    ///
+    /// `email` can be the address itself or `preferred` for the preferred email address.
+    pub fn get_email(&self, accountid: &str, email: &str) -> GGRResult<entities::EmailInfo> {
+        let path = format!("{}/emails/{}", Accounts::account_path(accountid)?, email);
+
+        self.call.execute::<(),entities::EmailInfo>("get account email", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /accounts/{account-id}/emails/{email-id}'
+    pub fn create_email(&self, accountid: &str, email: &str, preferred: Option<bool>, no_confirmation: Option<bool>) -> GGRResult<entities::EmailInfo> {
+        let path = format!("{}/emails/{}", Accounts::account_path(accountid)?, email);
+
+        let emailinput = entities::EmailInput {
+            email: Some(email.into()),
+            preferred: preferred,
+            no_confirmation: no_confirmation,
+        };
+
+        self.call.execute::<&entities::EmailInput,entities::EmailInfo>("create account email", &path, call::CallMethod::Put, Some(&&emailinput))
+    }
+
+    /// api function 'DELETE /accounts/{account-id}/emails/{email-id}'
+    pub fn delete_email(&self, accountid: &str, email: &str) -> GGRResult<()> {
+        let path = format!("{}/emails/{}", Accounts::account_path(accountid)?, email);
+
+        self.call.execute::<(),()>("delete account email", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'PUT /accounts/{account-id}/emails/{email-id}/preferred'
+    pub fn set_preferred_email(&self, accountid: &str, email: &str) -> GGRResult<()> {
+        let path = format!("{}/emails/{}/preferred", Accounts::account_path(accountid)?, email);
+
+        self.call.execute::<(),()>("set preferred email", &path, call::CallMethod::Put, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/sshkeys'
+    pub fn list_ssh_keys(&self, accountid: &str) -> GGRResult<Vec<entities::SshKeyInfo>> {
+        let path = format!("{}/sshkeys", Accounts::account_path(accountid)?);
+
+        self.call.execute::<(),Vec<entities::SshKeyInfo>>("list ssh keys", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/sshkeys/{ssh-key-id}'
+    pub fn get_ssh_key(&self, accountid: &str, seq: u64) -> GGRResult<entities::SshKeyInfo> {
+        let path = format!("{}/sshkeys/{}", Accounts::account_path(accountid)?, seq);
+
+        self.call.execute::<(),entities::SshKeyInfo>("get ssh key", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /accounts/{account-id}/sshkeys'
+    ///
+    /// The `key` is the public ssh key as found in a `id_rsa.pub` file.
+    pub fn add_ssh_key(&self, accountid: &str, key: &str) -> GGRResult<entities::SshKeyInfo> {
+        let path = format!("{}/sshkeys", Accounts::account_path(accountid)?);
+
+        self.call.execute_raw::<entities::SshKeyInfo>("add ssh key", &path, call::CallMethod::Post, key.trim().as_bytes(), "text/plain")
+    }
+
+    /// api function 'DELETE /accounts/{account-id}/sshkeys/{ssh-key-id}'
+    pub fn delete_ssh_key(&self, accountid: &str, seq: u64) -> GGRResult<()> {
+        let path = format!("{}/sshkeys/{}", Accounts::account_path(accountid)?, seq);
+
+        self.call.execute::<(),()>("delete ssh key", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/gpgkeys'
+    ///
+    /// returns a map of key ids to `GpgKeyInfo` entities
+    ///
+    /// V02.13
+    pub fn list_gpg_keys(&self, accountid: &str) -> GGRResult<HashMap<String, entities::GpgKeyInfo>> {
+        let path = format!("{}/gpgkeys", Accounts::account_path(accountid)?);
+
+        self.call.execute::<(),HashMap<String, entities::GpgKeyInfo>>("list gpg keys", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/gpgkeys/{gpg-key-id}'
+    ///
+    /// V02.13
+    pub fn get_gpg_key(&self, accountid: &str, keyid: &str) -> GGRResult<entities::GpgKeyInfo> {
+        let path = format!("{}/gpgkeys/{}", Accounts::account_path(accountid)?, keyid);
+
+        self.call.execute::<(),entities::GpgKeyInfo>("get gpg key", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /accounts/{account-id}/gpgkeys'
+    ///
+    /// Add and/or delete GPG keys. Returns a map of the changed key ids to `GpgKeyInfo` entities,
+    /// deleted keys are represented by an empty object.
+    ///
+    /// V02.13
+    pub fn add_delete_gpg_keys(&self, accountid: &str, gpgkeysinput: &entities::GpgKeysInput) -> GGRResult<HashMap<String, entities::GpgKeyInfo>> {
+        let path = format!("{}/gpgkeys", Accounts::account_path(accountid)?);
+
+        self.call.execute::<&entities::GpgKeysInput,HashMap<String, entities::GpgKeyInfo>>("add/delete gpg keys", &path, call::CallMethod::Post, Some(&gpgkeysinput))
+    }
+
+    /// api function 'DELETE /accounts/{account-id}/gpgkeys/{gpg-key-id}'
+    ///
+    /// V02.13
+    pub fn delete_gpg_key(&self, accountid: &str, keyid: &str) -> GGRResult<()> {
+        let path = format!("{}/gpgkeys/{}", Accounts::account_path(accountid)?, keyid);
+
+        self.call.execute::<(),()>("delete gpg key", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/starred.changes'
+    ///
+    /// returns the changes with the default star label
+    pub fn get_starred_changes(&self, accountid: &str) -> GGRResult<Vec<entities::ChangeInfo>> {
+        let path = format!("{}/starred.changes", Accounts::account_path(accountid)?);
+
+        self.call.execute::<(),Vec<entities::ChangeInfo>>("get starred changes", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /accounts/{account-id}/starred.changes/{change-id}'
+    pub fn star_change(&self, accountid: &str, changeid: &str) -> GGRResult<()> {
+        let path = format!("{}/starred.changes/{}", Accounts::account_path(accountid)?, changeid);
+
+        self.call.execute::<(),()>("star change", &path, call::CallMethod::Put, None)
+    }
+
+    /// api function 'DELETE /accounts/{account-id}/starred.changes/{change-id}'
+    pub fn unstar_change(&self, accountid: &str, changeid: &str) -> GGRResult<()> {
+        let path = format!("{}/starred.changes/{}", Accounts::account_path(accountid)?, changeid);
+
+        self.call.execute::<(),()>("unstar change", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/stars.changes'
+    ///
+    /// returns all changes with star labels, the labels are in the `stars` field
+    ///
+    /// V02.13
+    pub fn get_stars(&self, accountid: &str) -> GGRResult<Vec<entities::ChangeInfo>> {
+        let path = format!("{}/stars.changes", Accounts::account_path(accountid)?);
+
+        self.call.execute::<(),Vec<entities::ChangeInfo>>("get stars", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/stars.changes/{change-id}'
+    ///
+    /// returns the star labels of a change
+    ///
+    /// V02.13
+    pub fn get_star_labels(&self, accountid: &str, changeid: &str) -> GGRResult<Vec<String>> {
+        let path = format!("{}/stars.changes/{}", Accounts::account_path(accountid)?, changeid);
+
+        self.call.execute::<(),Vec<String>>("get star labels", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /accounts/{account-id}/stars.changes/{change-id}'
+    ///
+    /// Adds the star labels of `add` and removes the star labels of `remove`. Returns the star
+    /// labels of the change after the update.
+    ///
+    /// V02.13
+    pub fn update_star_labels(&self, accountid: &str, changeid: &str, add: Vec<&str>, remove: Vec<&str>) -> GGRResult<Vec<String>> {
+        let path = format!("{}/stars.changes/{}", Accounts::account_path(accountid)?, changeid);
+
+        let starsinput = entities::StarsInput {
+            add: if add.is_empty() { None } else { Some(add.iter().map(|s| s.to_string()).collect()) },
+            remove: if remove.is_empty() { None } else { Some(remove.iter().map(|s| s.to_string()).collect()) },
+        };
+
+        self.call.execute::<&entities::StarsInput,Option<Vec<String>>>("update star labels", &path, call::CallMethod::Post, Some(&&starsinput))
+            .map(|labels| labels.unwrap_or_default())
+    }
+
+    /// api function 'GET /accounts/{account-id}/capabilities'
+    ///
+    /// With `filter` only the named capabilities are returned (eg. `createProject`).
+    pub fn list_capabilities(&mut self, accountid: &str, filter: Option<Vec<&str>>) -> GGRResult<entities::CapabilityInfo> {
+        let path = format!("{}/capabilities", Accounts::account_path(accountid)?);
+
+        let querystring = filter.unwrap_or_default()
+            .iter()
+            .map(|q| format!("q={}", call::encode_query_value(q)))
+            .collect::<Vec<String>>()
+            .join("&");
+        self.call.set_url_query(if querystring.is_empty() { None } else { Some(&querystring) });
+
+        self.call.execute::<(),entities::CapabilityInfo>("list account capabilities", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /accounts/{account-id}/capabilities/{capability-id}'
+    ///
+    /// returns `true` if the user has the capability
+    pub fn check_capability(&self, accountid: &str, capability: &str) -> GGRResult<bool> {
+        let path = format!("{}/capabilities/{}", Accounts::account_path(accountid)?, capability);
+
+        match self.call.execute::<(),String>("check account capability", &path, call::CallMethod::Get, None) {
+            Ok(_) => Ok(true),
+            Err(GGRError::GerritApiError(GerritError::GerritApi(404, _))) => Ok(false),
+            Err(x) => Err(x),
+        }
+    }
+}
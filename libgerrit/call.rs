@@ -11,6 +11,39 @@ use url;
 
 use error::GGRResult;
 use error::GGRError;
+use error::GerritError;
+
+/// percent encode a single path segment
+///
+/// Project, branch and group names can contain slashes which have to be encoded when they are used
+/// as `{project-name}` or `{branch-id}` within an endpoint path.
+///
+/// # Examples
+///
+/// ```rust
+/// # use libgerrit::call::encode_path_segment;
+/// assert_eq!("platform%2Fbuild", encode_path_segment("platform/build"));
+/// assert_eq!("master", encode_path_segment("master"));
+/// ```
+pub fn encode_path_segment(segment: &str) -> String {
+    url::percent_encoding::utf8_percent_encode(segment, url::percent_encoding::PATH_SEGMENT_ENCODE_SET).to_string()
+}
+
+/// percent encode a single value of a query string
+///
+/// Values like project names or search queries can contain `&`, `=` or `+` which would otherwise
+/// change the meaning of the query string.
+///
+/// # Examples
+///
+/// ```rust
+/// # use libgerrit::call::encode_query_value;
+/// assert_eq!("platform%2Fbuild", encode_query_value("platform/build"));
+/// assert_eq!("a%26b%3Dc", encode_query_value("a&b=c"));
+/// ```
+pub fn encode_query_value(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
 
 /// interface function for `handle_req`, set some fields if body has content
 fn send_req<W: Write>(handle: &mut curl::easy::Easy,
@@ -112,6 +145,28 @@ impl Call {
     /// call the do_request function two times. One with digest and the other with basic http
     /// authentication methods. The first success returnes a CallResponse
     pub fn request<S: Serialize>(&self, method: CallMethod, path: &str, body: Option<&S>) -> GGRResult<CallResponse> {
+        let body = match body {
+            Some(body) => {
+                let mut body_bytes: Vec<u8> = vec![];
+                serde_json::to_writer(&mut body_bytes, &body)?;
+                Some(body_bytes)
+            },
+            None => None,
+        };
+
+        self.request_bytes(method, path, body.as_ref().map(|b| (&b[..], "application/json")))
+    }
+
+    /// same as `request` but the body is send as it is with the given content type
+    ///
+    /// Some gerrit endpoints (eg. adding of ssh keys) expect a raw request body and not a json
+    /// formated one.
+    pub fn request_raw(&self, method: CallMethod, path: &str, body: &[u8], content_type: &str) -> GGRResult<CallResponse> {
+        self.request_bytes(method, path, Some((body, content_type)))
+    }
+
+    /// low level request function with an optional body and his content type
+    fn request_bytes(&self, method: CallMethod, path: &str, body: Option<(&[u8], &str)>) -> GGRResult<CallResponse> {
         let mut sendurl = self.base.clone();
         // double replace for pathes with three ///.
         let complete_path = format!("{}/{}", sendurl.path(), path).replace("//", "/").replace("//", "/");
@@ -124,8 +179,8 @@ impl Call {
             curl::easy::Auth::new().basic(true),
         ) {
             let mut call_request = try!(self.do_request(&method, &sendurl.to_owned().into_string()));
-            if let Some(body) = body {
-                call_request.with_json_body(&body).ok();
+            if let Some((body, content_type)) = body {
+                call_request.with_raw_body(body, content_type).ok();
             }
 
             try!(call_request.handle.http_auth(am));
@@ -141,6 +196,36 @@ impl Call {
         Err(GGRError::General("No Authentication algorithm found for your gerrit server. 'basic' and 'digest' tested".into()))
     }
 
+    /// generic helper function for calling of gerrit endpoints
+    ///
+    /// The `desc` parameter is a short description for error messages, its embedded into 'Problem
+    /// '...' with <DESC>'.
+    /// The call is executed with the `path` parameter and the `httpmethod` with `uploaddata` for
+    /// `Put` and `Post` http methods.
+    pub fn execute<INPUT,OUTPUT>(&self, desc: &str, path: &str, httpmethod: CallMethod, uploaddata: Option<&INPUT>) -> GGRResult<OUTPUT>
+    where INPUT: Serialize + fmt::Debug,
+          OUTPUT: DeserializeOwned
+    {
+        match self.request(httpmethod, path, uploaddata) {
+            Ok(cr) => cr.convert_gerrit::<OUTPUT>(),
+            Err(x) => {
+                Err(GGRError::General(format!("Problem '{}' with {}", x, desc)))
+            }
+        }
+    }
+
+    /// same as `execute` but with a raw request body of type `content_type`
+    pub fn execute_raw<OUTPUT>(&self, desc: &str, path: &str, httpmethod: CallMethod, uploaddata: &[u8], content_type: &str) -> GGRResult<OUTPUT>
+    where OUTPUT: DeserializeOwned
+    {
+        match self.request_raw(httpmethod, path, uploaddata, content_type) {
+            Ok(cr) => cr.convert_gerrit::<OUTPUT>(),
+            Err(x) => {
+                Err(GGRError::General(format!("Problem '{}' with {}", x, desc)))
+            }
+        }
+    }
+
     /// Convenience method that performs a `GET` request.
     pub fn get(&self, path: &str) -> GGRResult<CallResponse> {
         self.request::<String>(CallMethod::Get, path, None)
@@ -223,6 +308,14 @@ impl<'a> CallRequest<'a> {
         Ok(self)
     }
 
+    /// sets a raw request body with content type `content_type` for the request.
+    pub fn with_raw_body(&mut self, body: &[u8], content_type: &str) -> GGRResult<&mut CallRequest<'a>> {
+        debug!("sending {} data ({} bytes) '{:?}'", content_type, body.len(), String::from_utf8_lossy(body));
+        self.body = Some(body.to_vec());
+        self.headers.append(&format!("Content-Type: {}", content_type))?;
+        Ok(self)
+    }
+
     /// sets the JSON request body for the request.
     pub fn with_json_body<S: Serialize>(&mut self, body: &S) -> GGRResult<&mut CallRequest<'a>> {
         let mut body_bytes: Vec<u8> = vec![];
@@ -343,6 +436,24 @@ impl CallResponse {
         self.to_result().and_then(|x| x.deserialize())
     }
 
+    /// Like `convert` but with the status code handling of the gerrit REST api.
+    ///
+    /// Status code 204 (no content) and some endpoints with status code 200 (eg. `wip`) have no
+    /// body what we can convert. In this case the `OUTPUT` is build from a json `null` value, which
+    /// works for `()` and `Option<T>` types.
+    pub fn convert_gerrit<OUTPUT: DeserializeOwned>(self) -> GGRResult<OUTPUT> {
+        let empty = self.body.as_ref().map_or(true, |body| body.iter().all(|c| c.is_ascii_whitespace()));
+
+        match self.status() {
+            200 | 201 | 202 | 203 | 205 if empty => Ok(serde_json::from_value(serde_json::Value::Null)?),
+            200 | 201 | 202 | 203 | 205 => self.convert::<OUTPUT>(),
+            204 => Ok(serde_json::from_value(serde_json::Value::Null)?),
+            status => {
+                Err(GGRError::GerritApiError(GerritError::GerritApi(status, String::from_utf8(self.get_body().unwrap_or_else(|| "no cause from server".into()))?)))
+            },
+        }
+    }
+
     /// Iterates over the headers.
     #[allow(dead_code)]
     pub fn headers(&self) -> Headers {
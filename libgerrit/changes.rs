@@ -1,4 +1,5 @@
 
+use base64;
 use call;
 use config;
 use error::GGRError;
@@ -7,6 +8,7 @@ use error::GerritError;
 use entities;
 use serde;
 use std;
+use std::collections::HashMap;
 use url;
 
 const ENDPOINT: &'static str = "/a/changes";
@@ -67,28 +69,43 @@ impl<'de> Changes {
         labelstring
     }
 
+    /// converts a notify string to a `NotifyHandling`
+    ///
+    /// notify is one of `none`, `owner`, `owner_reviewers` or `all`. Unknown values are returned
+    /// as `GerritError::UnknownNotify`.
+    pub fn build_notify(notify: Option<&str>) -> GGRResult<Option<entities::NotifyHandling>> {
+        match notify {
+            None => Ok(None),
+            Some("none") => Ok(Some(entities::NotifyHandling::NONE)),
+            Some("owner") => Ok(Some(entities::NotifyHandling::OWNER)),
+            Some("owner_reviewer") | Some("owner_reviewers") => Ok(Some(entities::NotifyHandling::OWNER_REVIEWERS)),
+            Some("all") => Ok(Some(entities::NotifyHandling::ALL)),
+            Some(x) => Err(GGRError::GerritApiError(GerritError::UnknownNotify(x.into()))),
+        }
+    }
+
     /// generic helper function for calling of call object
     ///
-    /// The `desc` parameter is a short description for  error messages, its embedded into 'Problem
-    /// '...' with <DESC>'.
-    /// The call is executed with the `path` parameter and the `httpmethod` with `uploaddata` for
-    /// `Put` and `Post` http methods.
+    /// See `call::Call::execute` for a description of the parameters.
     fn execute<INPUT,OUTPUT>(c: &Changes, desc: &str, path: &str, httpmethod: call::CallMethod, uploaddata: Option<&INPUT>) -> GGRResult<OUTPUT>
     where INPUT: serde::Serialize + std::fmt::Debug,
           OUTPUT: serde::de::DeserializeOwned
     {
-        match c.call.request(httpmethod, path, uploaddata) {
+        c.call.execute(desc, path, httpmethod, uploaddata)
+    }
+
+    /// helper function for endpoints which return base64 encoded content instead of json
+    ///
+    /// returns the decoded content
+    fn get_base64(c: &Changes, desc: &str, path: &str) -> GGRResult<Vec<u8>> {
+        match c.call.get(path) {
             Ok(cr) => {
-                match cr.status() {
-                    200 | 201 | 202 | 203 | 205 => cr.convert::<OUTPUT>(),
-                    /*
-                     * We need handling of 204 returne code. 204 means no body text what we can
-                     * convert. But the converter try it and crashes with a json failure
-                     * "JsonError(ErrorImpl { code: EofWhileParsingValue, line: 1, column: 0 })"
-                     *
-                     * 204 => cr.convert::<OUTPUT>(),
-                     */
-                    status => { Err(GGRError::GerritApiError(GerritError::GerritApi(status, String::from_utf8(cr.get_body().unwrap_or_else(|| "no cause from server".into()))?))) },
+                let body = cr.get_body().unwrap_or_default();
+                if cr.ok() {
+                    let content: Vec<u8> = body.into_iter().filter(|c| !c.is_ascii_whitespace()).collect();
+                    Ok(base64::decode(&content)?)
+                } else {
+                    Err(GGRError::GerritApiError(GerritError::GerritApi(cr.status(), String::from_utf8(body)?)))
                 }
             },
             Err(x) => {
@@ -159,6 +176,218 @@ impl<'de> Changes {
         Changes::execute::<(),entities::ChangeInfo>(self, "get change detail", &path, call::CallMethod::Get, None)
     }
 
+    /// api function 'GET /changes/{change-id}/in'
+    ///
+    /// returns the branches and tags a merged change is included in
+    pub fn get_included_in(&self, changeid: &str) -> GGRResult<entities::IncludedInInfo> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/in", ENDPOINT, changeid);
+
+        Changes::execute::<(),entities::IncludedInInfo>(self, "get included in", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/check'
+    ///
+    /// The `problems` of the returned `ChangeInfo` lists the consistency problems of the change.
+    pub fn check_change(&self, changeid: &str) -> GGRResult<entities::ChangeInfo> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/check", ENDPOINT, changeid);
+
+        Changes::execute::<(),entities::ChangeInfo>(self, "check change", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /changes/{change-id}/check'
+    ///
+    /// The `problems` of the returned `ChangeInfo` contain the outcome of the fixes.
+    pub fn fix_change(&self, changeid: &str, fixinput: &entities::FixInput) -> GGRResult<entities::ChangeInfo> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/check", ENDPOINT, changeid);
+
+        Changes::execute::<&entities::FixInput,entities::ChangeInfo>(self, "fix change", &path, call::CallMethod::Post, Some(&fixinput))
+    }
+
+    /// api function 'GET /changes/{change-id}/topic'
+    ///
+    /// returns an empty string if the change has no topic
+    pub fn get_topic(&self, changeid: &str) -> GGRResult<String> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/topic", ENDPOINT, changeid);
+
+        Changes::execute::<(),Option<String>>(self, "get topic", &path, call::CallMethod::Get, None)
+            .map(|topic| topic.unwrap_or_default())
+    }
+
+    /// api function 'PUT /changes/{change-id}/topic'
+    ///
+    /// A `None` or empty `topic` removes the topic from the change.
+    pub fn set_topic(&self, changeid: &str, topic: Option<&str>) -> GGRResult<String> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/topic", ENDPOINT, changeid);
+
+        let topicinput = entities::TopicInput {
+            topic: topic.map(|s| s.to_string()),
+        };
+
+        Changes::execute::<&entities::TopicInput,Option<String>>(self, "set topic", &path, call::CallMethod::Put, Some(&&topicinput))
+            .map(|topic| topic.unwrap_or_default())
+    }
+
+    /// api function 'DELETE /changes/{change-id}/topic'
+    pub fn delete_topic(&self, changeid: &str) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/topic", ENDPOINT, changeid);
+
+        Changes::execute::<(),()>(self, "delete topic", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'POST /changes/{change-id}/wip'
+    ///
+    /// V02.15
+    pub fn set_work_in_progress(&self, changeid: &str, message: Option<&str>) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/wip", ENDPOINT, changeid);
+
+        let wipinput = entities::WorkInProgressInput {
+            message: message.map(|s| s.to_string()),
+        };
+
+        Changes::execute::<&entities::WorkInProgressInput,()>(self, "set work in progress", &path, call::CallMethod::Post, Some(&&wipinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/ready'
+    ///
+    /// V02.15
+    pub fn set_ready_for_review(&self, changeid: &str, message: Option<&str>) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/ready", ENDPOINT, changeid);
+
+        let wipinput = entities::WorkInProgressInput {
+            message: message.map(|s| s.to_string()),
+        };
+
+        Changes::execute::<&entities::WorkInProgressInput,()>(self, "set ready for review", &path, call::CallMethod::Post, Some(&&wipinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/private'
+    ///
+    /// V02.15
+    pub fn mark_private(&self, changeid: &str, message: Option<&str>) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/private", ENDPOINT, changeid);
+
+        let privateinput = entities::PrivateInput {
+            message: message.map(|s| s.to_string()),
+        };
+
+        Changes::execute::<&entities::PrivateInput,()>(self, "mark private", &path, call::CallMethod::Post, Some(&&privateinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/private.delete'
+    ///
+    /// The POST variant of 'DELETE /changes/{change-id}/private' is used to transport the message.
+    ///
+    /// V02.15
+    pub fn unmark_private(&self, changeid: &str, message: Option<&str>) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/private.delete", ENDPOINT, changeid);
+
+        let privateinput = entities::PrivateInput {
+            message: message.map(|s| s.to_string()),
+        };
+
+        Changes::execute::<&entities::PrivateInput,()>(self, "unmark private", &path, call::CallMethod::Post, Some(&&privateinput))
+    }
+
+    /// api function 'PUT /changes/{change-id}/ignore' and 'PUT /changes/{change-id}/unignore'
+    ///
+    /// Ignored changes are not shown in the dashboard and no emails are sent for it.
+    ///
+    /// V02.15
+    pub fn ignore_change(&self, changeid: &str, ignore: bool) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/{}", ENDPOINT, changeid, if ignore { "ignore" } else { "unignore" });
+
+        Changes::execute::<(),()>(self, "ignore change", &path, call::CallMethod::Put, None)
+    }
+
+    /// api function 'PUT /changes/{change-id}/mute' and 'PUT /changes/{change-id}/unmute'
+    ///
+    /// A muted change is marked as read until a new patch set is uploaded.
+    ///
+    /// V02.15
+    pub fn mute_change(&self, changeid: &str, mute: bool) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/{}", ENDPOINT, changeid, if mute { "mute" } else { "unmute" });
+
+        Changes::execute::<(),()>(self, "mute change", &path, call::CallMethod::Put, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/hashtags'
+    pub fn get_hashtags(&self, changeid: &str) -> GGRResult<Vec<String>> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/hashtags", ENDPOINT, changeid);
+
+        Changes::execute::<(),Vec<String>>(self, "get hashtags", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /changes/{change-id}/hashtags'
+    ///
+    /// Adds the hashtags of `add` and removes the hashtags of `remove`. Returns the hashtags of the
+    /// change after the update.
+    pub fn set_hashtags(&self, changeid: &str, add: Vec<&str>, remove: Vec<&str>) -> GGRResult<Vec<String>> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/hashtags", ENDPOINT, changeid);
+
+        let hashtagsinput = entities::HashtagsInput {
+            add: if add.is_empty() { None } else { Some(add.iter().map(|s| s.to_string()).collect()) },
+            remove: if remove.is_empty() { None } else { Some(remove.iter().map(|s| s.to_string()).collect()) },
+        };
+
+        Changes::execute::<&entities::HashtagsInput,Vec<String>>(self, "set hashtags", &path, call::CallMethod::Post, Some(&&hashtagsinput))
+    }
+
     /// api function `GET /changes/{change-id}/reviewers/'
     pub fn get_reviewers(&self, changeid: &str) -> GGRResult<Vec<entities::ReviewerInfo>> {
         if changeid.is_empty() {
@@ -171,20 +400,37 @@ impl<'de> Changes {
     }
 
     /// api function 'POST /changes/{change-id}/reviewers'
-    pub fn add_reviewer(&self, changeid: &str, reviewer: &str) -> GGRResult<entities::AddReviewerResult> {
-        if changeid.is_empty() || reviewer.is_empty() {
+    ///
+    /// The `state` of the `ReviewerInput` selects between REVIEWER and CC. Groups with many members
+    /// need `confirmed`, otherwise the returned `AddReviewerResult` has `confirm` set.
+    pub fn add_reviewer(&self, changeid: &str, reviewerinput: &entities::ReviewerInput) -> GGRResult<entities::AddReviewerResult> {
+        if changeid.is_empty() || reviewerinput.reviewer.is_empty() {
             return Err(GGRError::GerritApiError(GerritError::GetReviewerListProblem("changeid or reviewer is empty".into())));
         }
 
         let path = format!("{}/{}/reviewers", ENDPOINT, changeid);
 
-        let reviewerinput = entities::ReviewerInput {
-                reviewer: reviewer.into(),
-                confirmed: None,
-                state: None,
-        };
+        Changes::execute::<&entities::ReviewerInput,entities::AddReviewerResult>(self, "add reviewer", &path, call::CallMethod::Post, Some(&reviewerinput))
+    }
+
+    /// api function 'GET /changes/{change-id}/suggest_reviewers'
+    ///
+    /// returns accounts and groups matching `query` which can be added as reviewer to the change.
+    /// `limit` restricts the count of returned suggestions.
+    pub fn suggest_reviewers(&mut self, changeid: &str, query: &str, limit: Option<usize>) -> GGRResult<Vec<entities::SuggestedReviewerInfo>> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/suggest_reviewers", ENDPOINT, changeid);
+
+        let mut querystring = format!("q={}", call::encode_query_value(query));
+        if let Some(limit) = limit {
+            querystring = format!("{}&n={}", querystring, limit);
+        }
+        self.call.set_url_query(Some(&querystring));
 
-        Changes::execute::<&entities::ReviewerInput,entities::AddReviewerResult>(self, "add reviewer", &path, call::CallMethod::Post, Some(&&reviewerinput))
+        Changes::execute::<(),Vec<entities::SuggestedReviewerInfo>>(self, "suggest reviewers", &path, call::CallMethod::Get, None)
     }
 
     /// api function 'DELETE /changes/{change-id}/reviewers/{account-id}'
@@ -198,6 +444,40 @@ impl<'de> Changes {
         Changes::execute::<(),()>(self, "deleting reviewer", &path, call::CallMethod::Delete, None)
     }
 
+    /// api function 'GET /changes/{change-id}/reviewers/{account-id}/votes/'
+    ///
+    /// returns a map of label names to the votes of `reviewer`
+    pub fn list_votes(&self, changeid: &str, reviewer: &str) -> GGRResult<HashMap<String, i8>> {
+        if changeid.is_empty() || reviewer.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::GetReviewerListProblem("changeid or reviewer is empty".into())));
+        }
+
+        let path = format!("{}/{}/reviewers/{}/votes/", ENDPOINT, changeid, call::encode_path_segment(reviewer));
+
+        Changes::execute::<(),HashMap<String, i8>>(self, "list votes", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /changes/{change-id}/reviewers/{account-id}/votes/{label-id}/delete'
+    ///
+    /// The POST variant of 'DELETE /changes/{change-id}/reviewers/{account-id}/votes/{label-id}' is
+    /// used, because a request body for DELETE is deprecated.
+    ///
+    /// notify is one of `none`, `owner`, `owner_reviewers` or `all`.
+    pub fn delete_vote(&self, changeid: &str, reviewer: &str, label: &str, notify: Option<&str>) -> GGRResult<()> {
+        if changeid.is_empty() || reviewer.is_empty() || label.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::GetReviewerListProblem("changeid, reviewer or label is empty".into())));
+        }
+
+        let path = format!("{}/{}/reviewers/{}/votes/{}/delete", ENDPOINT, changeid, call::encode_path_segment(reviewer), label);
+
+        let deletevoteinput = entities::DeleteVoteInput {
+            label: Some(label.into()),
+            notify: Changes::build_notify(notify)?,
+        };
+
+        Changes::execute::<&entities::DeleteVoteInput,()>(self, "delete vote", &path, call::CallMethod::Post, Some(&&deletevoteinput))
+    }
+
     /// api function 'POST /changes/{change-id}/abandon'
     ///
     /// notify is one of `none`, `owner`, `owner_reviewers` or `all`.
@@ -208,21 +488,9 @@ impl<'de> Changes {
 
         let path = format!("{}/{}/abandon", ENDPOINT, changeid);
 
-        let notify = match notify {
-            Some(notify) => {
-                match notify {
-                    "all" => Some(entities::AbandonInputNotify::ALL),
-                    "owner" => Some(entities::AbandonInputNotify::OWNER),
-                    "owner_reviewer" => Some(entities::AbandonInputNotify::OWNER_REVIEWERS),
-                    _ => Some(entities::AbandonInputNotify::NONE),
-                }
-            },
-            None => None
-        };
-
         let abandoninput = entities::AbandonInput {
                 message: message.map(|s| s.to_string()),
-                notify: notify,
+                notify: Changes::build_notify(notify)?,
         };
 
         Changes::execute::<&entities::AbandonInput,entities::ChangeInfo>(self, "abandon change", &path, call::CallMethod::Post, Some(&&abandoninput))
@@ -243,27 +511,615 @@ impl<'de> Changes {
         Changes::execute::<&entities::RestoreInput,entities::ChangeInfo>(self, "restore change", &path, call::CallMethod::Post, Some(&&restoreinput))
     }
 
-    /// api function 'POST /changes/{change-id}/revisions/{revision-id}/review'
-    pub fn set_review(&self, changeid: &str, revisionid: &str, message: Option<&str>, labels: Option<entities::ReviewInfo>) -> GGRResult<entities::ReviewInfo> {
-        if changeid.is_empty() || revisionid.is_empty() {
+    /// api function 'POST /changes/{change-id}/revert'
+    ///
+    /// returns the newly created revert change
+    pub fn revert_change(&self, changeid: &str, message: Option<&str>) -> GGRResult<entities::ChangeInfo> {
+        if changeid.is_empty() {
             return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
         }
 
-        let path = format!("{}/{}/revisions/{}/review", ENDPOINT, changeid, revisionid);
+        let path = format!("{}/{}/revert", ENDPOINT, changeid);
+
+        let revertinput = entities::RevertInput {
+            message: message.map(|s| s.to_string()),
+        };
+
+        Changes::execute::<&entities::RevertInput,entities::ChangeInfo>(self, "revert change", &path, call::CallMethod::Post, Some(&&revertinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/rebase'
+    ///
+    /// Without `base` the change is rebased on the tip of the destination branch or on the
+    /// current patch set of his parent change.
+    pub fn rebase_change(&self, changeid: &str, base: Option<&str>) -> GGRResult<entities::ChangeInfo> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/rebase", ENDPOINT, changeid);
+
+        let rebaseinput = entities::RebaseInput {
+            base: base.map(|s| s.to_string()),
+        };
+
+        Changes::execute::<&entities::RebaseInput,entities::ChangeInfo>(self, "rebase change", &path, call::CallMethod::Post, Some(&&rebaseinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/revisions/{revision-id}/rebase'
+    pub fn rebase_revision(&self, changeid: &str, revisionid: &str, base: Option<&str>) -> GGRResult<entities::ChangeInfo> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
 
-        use std::collections::HashMap;
+        let path = format!("{}/{}/revisions/{}/rebase", ENDPOINT, changeid, revisionid);
 
-        #[derive(Serialize, Debug)]
-        struct Review {
-            message: Option<String>,
-            labels: HashMap<String, i8>,
+        let rebaseinput = entities::RebaseInput {
+            base: base.map(|s| s.to_string()),
         };
 
-        let review = Review {
+        Changes::execute::<&entities::RebaseInput,entities::ChangeInfo>(self, "rebase revision", &path, call::CallMethod::Post, Some(&&rebaseinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/move'
+    ///
+    /// V02.13
+    pub fn move_change(&self, changeid: &str, destination_branch: &str, message: Option<&str>) -> GGRResult<entities::ChangeInfo> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+        if destination_branch.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::BranchNameEmpty));
+        }
+
+        let path = format!("{}/{}/move", ENDPOINT, changeid);
+
+        let moveinput = entities::MoveInput {
+            destination_branch: destination_branch.into(),
             message: message.map(|s| s.to_string()),
-            labels: labels.unwrap_or(entities::ReviewInfo{ labels: HashMap::new() }).labels,
         };
 
-        Changes::execute::<&Review,entities::ReviewInfo>(self, "set review", &path, call::CallMethod::Post, Some(&&review))
+        Changes::execute::<&entities::MoveInput,entities::ChangeInfo>(self, "move change", &path, call::CallMethod::Post, Some(&&moveinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/revisions/{revision-id}/cherrypick'
+    ///
+    /// returns the newly created change on the destination branch
+    pub fn cherry_pick_revision(&self, changeid: &str, revisionid: &str, cherrypickinput: &entities::CherryPickInput) -> GGRResult<entities::ChangeInfo> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/cherrypick", ENDPOINT, changeid, revisionid);
+
+        Changes::execute::<&entities::CherryPickInput,entities::ChangeInfo>(self, "cherry pick revision", &path, call::CallMethod::Post, Some(&cherrypickinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/submit'
+    ///
+    /// notify is one of `none`, `owner`, `owner_reviewers` or `all`.
+    pub fn submit_change(&self, changeid: &str, notify: Option<&str>) -> GGRResult<entities::ChangeInfo> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/submit", ENDPOINT, changeid);
+
+        let submitinput = entities::SubmitInput {
+            on_behalf_of: None,
+            notify: Changes::build_notify(notify)?,
+        };
+
+        Changes::execute::<&entities::SubmitInput,entities::ChangeInfo>(self, "submit change", &path, call::CallMethod::Post, Some(&&submitinput))
+    }
+
+    /// api function 'GET /changes/{change-id}/submitted_together'
+    ///
+    /// returns all changes which would be submitted together with `changeid`, including
+    /// `changeid` itself. The `features` are additional fields of the returned `ChangeInfo`
+    /// entities like `CURRENT_REVISION`.
+    ///
+    /// V02.13
+    pub fn submitted_together(&mut self, changeid: &str, features: Option<Vec<&str>>) -> GGRResult<entities::SubmittedTogetherInfo> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let mut features = features.unwrap_or_default();
+        features.push("NON_VISIBLE_CHANGES");
+        let query = Changes::build_label_string(Some(features));
+
+        let path = format!("{}/{}/submitted_together", ENDPOINT, changeid);
+
+        self.call.set_url_query(Some(&query));
+
+        Changes::execute::<(),entities::SubmittedTogetherInfo>(self, "submitted together", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/edit'
+    ///
+    /// returns `None` if the change has no change edit
+    pub fn get_edit(&self, changeid: &str) -> GGRResult<Option<entities::EditInfo>> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit", ENDPOINT, changeid);
+
+        Changes::execute::<(),Option<entities::EditInfo>>(self, "get change edit", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /changes/{change-id}/edit'
+    ///
+    /// creates an empty change edit based on the current patch set
+    pub fn create_edit(&self, changeid: &str) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit", ENDPOINT, changeid);
+
+        Changes::execute::<(),()>(self, "create change edit", &path, call::CallMethod::Post, None)
     }
+
+    /// api function 'DELETE /changes/{change-id}/edit'
+    pub fn delete_edit(&self, changeid: &str) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit", ENDPOINT, changeid);
+
+        Changes::execute::<(),()>(self, "delete change edit", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/edit/{path}'
+    ///
+    /// returns the base64 decoded content of `file` in the change edit
+    pub fn get_edit_file(&self, changeid: &str, file: &str) -> GGRResult<Vec<u8>> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit/{}", ENDPOINT, changeid, call::encode_path_segment(file));
+
+        Changes::get_base64(self, "get change edit file", &path)
+    }
+
+    /// api function 'PUT /changes/{change-id}/edit/{path}'
+    ///
+    /// replaces the content of `file` in the change edit, the change edit is created if needed
+    pub fn put_edit_file(&self, changeid: &str, file: &str, content: &[u8]) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit/{}", ENDPOINT, changeid, call::encode_path_segment(file));
+
+        self.call.execute_raw::<()>("put change edit file", &path, call::CallMethod::Put, content, "application/octet-stream")
+    }
+
+    /// api function 'DELETE /changes/{change-id}/edit/{path}'
+    pub fn delete_edit_file(&self, changeid: &str, file: &str) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit/{}", ENDPOINT, changeid, call::encode_path_segment(file));
+
+        Changes::execute::<(),()>(self, "delete change edit file", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'POST /changes/{change-id}/edit' with `old_path` and `new_path`
+    pub fn rename_edit_file(&self, changeid: &str, old_path: &str, new_path: &str) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit", ENDPOINT, changeid);
+
+        let changeeditinput = entities::ChangeEditInput {
+            restore_path: None,
+            old_path: Some(old_path.into()),
+            new_path: Some(new_path.into()),
+        };
+
+        Changes::execute::<&entities::ChangeEditInput,()>(self, "rename change edit file", &path, call::CallMethod::Post, Some(&&changeeditinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/edit' with `restore_path`
+    pub fn restore_edit_file(&self, changeid: &str, file: &str) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit", ENDPOINT, changeid);
+
+        let changeeditinput = entities::ChangeEditInput {
+            restore_path: Some(file.into()),
+            old_path: None,
+            new_path: None,
+        };
+
+        Changes::execute::<&entities::ChangeEditInput,()>(self, "restore change edit file", &path, call::CallMethod::Post, Some(&&changeeditinput))
+    }
+
+    /// api function 'GET /changes/{change-id}/edit:message'
+    ///
+    /// returns the commit message of the change edit
+    pub fn get_edit_message(&self, changeid: &str) -> GGRResult<String> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit:message", ENDPOINT, changeid);
+
+        let message = Changes::execute::<(),String>(self, "get change edit message", &path, call::CallMethod::Get, None)?;
+
+        Changes::decode_base64_message(&message)
+    }
+
+    /// decodes the base64 encoded message which `edit:message` returns as json string
+    fn decode_base64_message(message: &str) -> GGRResult<String> {
+        Ok(String::from_utf8(base64::decode(message.trim())?)?)
+    }
+
+    /// api function 'PUT /changes/{change-id}/edit:message'
+    ///
+    /// The Change-Id footer of `message` has to be unchanged.
+    pub fn set_edit_message(&self, changeid: &str, message: &str) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit:message", ENDPOINT, changeid);
+
+        let messageinput = entities::ChangeEditMessageInput {
+            message: message.into(),
+        };
+
+        Changes::execute::<&entities::ChangeEditMessageInput,()>(self, "set change edit message", &path, call::CallMethod::Put, Some(&&messageinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/edit:publish'
+    ///
+    /// notify is one of `none`, `owner`, `owner_reviewers` or `all`.
+    pub fn publish_edit(&self, changeid: &str, notify: Option<&str>) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit:publish", ENDPOINT, changeid);
+
+        let publishinput = entities::PublishChangeEditInput {
+            notify: Changes::build_notify(notify)?,
+        };
+
+        Changes::execute::<&entities::PublishChangeEditInput,()>(self, "publish change edit", &path, call::CallMethod::Post, Some(&&publishinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/edit:rebase'
+    ///
+    /// rebases the change edit on the current patch set
+    pub fn rebase_edit(&self, changeid: &str) -> GGRResult<()> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/edit:rebase", ENDPOINT, changeid);
+
+        Changes::execute::<(),()>(self, "rebase change edit", &path, call::CallMethod::Post, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/comments'
+    ///
+    /// returns a map of file paths to the published comments of all revisions
+    pub fn list_change_comments(&self, changeid: &str) -> GGRResult<HashMap<String, Vec<entities::CommentInfo>>> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/comments", ENDPOINT, changeid);
+
+        Changes::execute::<(),HashMap<String, Vec<entities::CommentInfo>>>(self, "list change comments", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/drafts'
+    ///
+    /// returns a map of file paths to the draft comments of all revisions of the calling user
+    pub fn list_change_drafts(&self, changeid: &str) -> GGRResult<HashMap<String, Vec<entities::CommentInfo>>> {
+        if changeid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/drafts", ENDPOINT, changeid);
+
+        Changes::execute::<(),HashMap<String, Vec<entities::CommentInfo>>>(self, "list change drafts", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/comments/'
+    ///
+    /// returns a map of file paths to the published comments of the revision
+    pub fn list_revision_comments(&self, changeid: &str, revisionid: &str) -> GGRResult<HashMap<String, Vec<entities::CommentInfo>>> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/comments/", ENDPOINT, changeid, revisionid);
+
+        Changes::execute::<(),HashMap<String, Vec<entities::CommentInfo>>>(self, "list revision comments", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/comments/{comment-id}'
+    pub fn get_comment(&self, changeid: &str, revisionid: &str, commentid: &str) -> GGRResult<entities::CommentInfo> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/comments/{}", ENDPOINT, changeid, revisionid, commentid);
+
+        Changes::execute::<(),entities::CommentInfo>(self, "get comment", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/drafts/'
+    ///
+    /// returns a map of file paths to the draft comments of the revision of the calling user
+    pub fn list_revision_drafts(&self, changeid: &str, revisionid: &str) -> GGRResult<HashMap<String, Vec<entities::CommentInfo>>> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/drafts/", ENDPOINT, changeid, revisionid);
+
+        Changes::execute::<(),HashMap<String, Vec<entities::CommentInfo>>>(self, "list revision drafts", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /changes/{change-id}/revisions/{revision-id}/drafts'
+    ///
+    /// The `path` of the `CommentInput` is required.
+    pub fn create_draft(&self, changeid: &str, revisionid: &str, commentinput: &entities::CommentInput) -> GGRResult<entities::CommentInfo> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/drafts", ENDPOINT, changeid, revisionid);
+
+        Changes::execute::<&entities::CommentInput,entities::CommentInfo>(self, "create draft", &path, call::CallMethod::Put, Some(&commentinput))
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/drafts/{draft-id}'
+    pub fn get_draft(&self, changeid: &str, revisionid: &str, draftid: &str) -> GGRResult<entities::CommentInfo> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/drafts/{}", ENDPOINT, changeid, revisionid, draftid);
+
+        Changes::execute::<(),entities::CommentInfo>(self, "get draft", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /changes/{change-id}/revisions/{revision-id}/drafts/{draft-id}'
+    pub fn update_draft(&self, changeid: &str, revisionid: &str, draftid: &str, commentinput: &entities::CommentInput) -> GGRResult<entities::CommentInfo> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/drafts/{}", ENDPOINT, changeid, revisionid, draftid);
+
+        Changes::execute::<&entities::CommentInput,entities::CommentInfo>(self, "update draft", &path, call::CallMethod::Put, Some(&commentinput))
+    }
+
+    /// api function 'DELETE /changes/{change-id}/revisions/{revision-id}/drafts/{draft-id}'
+    pub fn delete_draft(&self, changeid: &str, revisionid: &str, draftid: &str) -> GGRResult<()> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/drafts/{}", ENDPOINT, changeid, revisionid, draftid);
+
+        Changes::execute::<(),()>(self, "delete draft", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/mergeable'
+    ///
+    /// With `other_branches` the `mergeable_into` field lists other branches this change could
+    /// merge into cleanly.
+    pub fn get_mergeable(&mut self, changeid: &str, revisionid: &str, other_branches: bool) -> GGRResult<entities::MergeableInfo> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/mergeable", ENDPOINT, changeid, revisionid);
+
+        self.call.set_url_query(if other_branches { Some("other-branches") } else { None });
+
+        Changes::execute::<(),entities::MergeableInfo>(self, "get mergeable", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/submit_type'
+    pub fn get_submit_type(&self, changeid: &str, revisionid: &str) -> GGRResult<String> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/submit_type", ENDPOINT, changeid, revisionid);
+
+        Changes::execute::<(),String>(self, "get submit type", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /changes/{change-id}/revisions/{revision-id}/test.submit_type'
+    ///
+    /// returns the submit type of the revision, computed with the Prolog rule of `ruleinput`
+    pub fn test_submit_type(&self, changeid: &str, revisionid: &str, ruleinput: &entities::RuleInput) -> GGRResult<String> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/test.submit_type", ENDPOINT, changeid, revisionid);
+
+        Changes::execute::<&entities::RuleInput,String>(self, "test submit type", &path, call::CallMethod::Post, Some(&ruleinput))
+    }
+
+    /// api function 'POST /changes/{change-id}/revisions/{revision-id}/test.submit_rule'
+    ///
+    /// returns the submit records of the revision, computed with the Prolog rule of `ruleinput`
+    pub fn test_submit_rule(&self, changeid: &str, revisionid: &str, ruleinput: &entities::RuleInput) -> GGRResult<Vec<entities::SubmitRecord>> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/test.submit_rule", ENDPOINT, changeid, revisionid);
+
+        Changes::execute::<&entities::RuleInput,Vec<entities::SubmitRecord>>(self, "test submit rule", &path, call::CallMethod::Post, Some(&ruleinput))
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/related'
+    ///
+    /// returns the relation chain of the revision, newest to oldest
+    pub fn get_related_changes(&self, changeid: &str, revisionid: &str) -> GGRResult<entities::RelatedChangesInfo> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/related", ENDPOINT, changeid, revisionid);
+
+        Changes::execute::<(),entities::RelatedChangesInfo>(self, "get related changes", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/files/'
+    ///
+    /// returns a map of file names to `FileInfo` entities.
+    ///
+    /// * `base`: revision to compare with, instead of the parent commit
+    /// * `parent`: compare against this parent (1 based) for merge commits
+    /// * `reviewed`: return only the files the caller has marked as reviewed; the values of the
+    ///   map are not set in this case
+    pub fn list_files(&mut self, changeid: &str, revisionid: &str, base: Option<&str>, parent: Option<u64>, reviewed: bool) -> GGRResult<HashMap<String, entities::FileInfo>> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/files/", ENDPOINT, changeid, revisionid);
+
+        let mut query = Vec::new();
+        if let Some(base) = base {
+            query.push(format!("base={}", base));
+        }
+        if let Some(parent) = parent {
+            query.push(format!("parent={}", parent));
+        }
+
+        if reviewed {
+            query.push("reviewed".into());
+            let querystring = query.join("&");
+            self.call.set_url_query(Some(&querystring));
+
+            let files = Changes::execute::<(),Vec<String>>(self, "list reviewed files", &path, call::CallMethod::Get, None)?;
+            return Ok(files.into_iter().map(|f| (f, entities::FileInfo::default())).collect());
+        }
+
+        let querystring = query.join("&");
+        self.call.set_url_query(if querystring.is_empty() { None } else { Some(&querystring) });
+
+        Changes::execute::<(),HashMap<String, entities::FileInfo>>(self, "list files", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/files/{file-id}/content'
+    ///
+    /// returns the base64 decoded content of `file`
+    pub fn get_content(&self, changeid: &str, revisionid: &str, file: &str) -> GGRResult<Vec<u8>> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/files/{}/content", ENDPOINT, changeid, revisionid, call::encode_path_segment(file));
+
+        Changes::get_base64(self, "get content", &path)
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/files/{file-id}/diff'
+    ///
+    /// * `base`: revision to compare with, instead of the parent commit
+    /// * `intraline`: add intraline differences to the `DiffContent` entities
+    pub fn get_diff(&mut self, changeid: &str, revisionid: &str, file: &str, base: Option<&str>, intraline: bool) -> GGRResult<entities::DiffInfo> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/files/{}/diff", ENDPOINT, changeid, revisionid, call::encode_path_segment(file));
+
+        let mut query = Vec::new();
+        if let Some(base) = base {
+            query.push(format!("base={}", base));
+        }
+        if intraline {
+            query.push("intraline".into());
+        }
+        let querystring = query.join("&");
+        self.call.set_url_query(if querystring.is_empty() { None } else { Some(&querystring) });
+
+        Changes::execute::<(),entities::DiffInfo>(self, "get diff", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /changes/{change-id}/revisions/{revision-id}/files/{file-id}/blame'
+    ///
+    /// With `base` the blame information for the base of the revision is returned.
+    ///
+    /// V02.13
+    pub fn get_blame(&mut self, changeid: &str, revisionid: &str, file: &str, base: bool) -> GGRResult<Vec<entities::BlameInfo>> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/files/{}/blame", ENDPOINT, changeid, revisionid, call::encode_path_segment(file));
+
+        self.call.set_url_query(if base { Some("base") } else { None });
+
+        Changes::execute::<(),Vec<entities::BlameInfo>>(self, "get blame", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /changes/{change-id}/revisions/{revision-id}/files/{file-id}/reviewed'
+    ///
+    /// The server answers with `201 Created`, or `200 OK` if the file was already marked as
+    /// reviewed, both without a body. Empty bodies are converted to `()` by
+    /// `CallResponse::convert_gerrit`.
+    pub fn set_reviewed(&self, changeid: &str, revisionid: &str, file: &str) -> GGRResult<()> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/files/{}/reviewed", ENDPOINT, changeid, revisionid, call::encode_path_segment(file));
+
+        Changes::execute::<(),()>(self, "set reviewed", &path, call::CallMethod::Put, None)
+    }
+
+    /// api function 'DELETE /changes/{change-id}/revisions/{revision-id}/files/{file-id}/reviewed'
+    pub fn delete_reviewed(&self, changeid: &str, revisionid: &str, file: &str) -> GGRResult<()> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/files/{}/reviewed", ENDPOINT, changeid, revisionid, call::encode_path_segment(file));
+
+        Changes::execute::<(),()>(self, "delete reviewed", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'POST /changes/{change-id}/revisions/{revision-id}/review'
+    pub fn set_review(&self, changeid: &str, revisionid: &str, reviewinput: &entities::ReviewInput) -> GGRResult<entities::ReviewResult> {
+        if changeid.is_empty() || revisionid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ChangeIDEmpty));
+        }
+
+        let path = format!("{}/{}/revisions/{}/review", ENDPOINT, changeid, revisionid);
+
+        Changes::execute::<&entities::ReviewInput,entities::ReviewResult>(self, "set review", &path, call::CallMethod::Post, Some(&reviewinput))
+    }
+}
+
+#[test]
+fn test_decode_base64_message() {
+    use serde_json;
+
+    let message: String = serde_json::from_str(r#""Zml4IHRoZSBidWcKCkNoYW5nZS1JZDogSTEyMzQK""#).unwrap();
+    assert_eq!("fix the bug\n\nChange-Id: I1234\n", Changes::decode_base64_message(&message).unwrap());
 }
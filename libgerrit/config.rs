@@ -3,10 +3,13 @@ use call;
 use error::GGRError;
 use error::GGRResult;
 use error::GerritError;
+use entities;
 use semver;
+use std::collections::HashMap;
 use url;
 
 const ENDPOINT: &'static str = "/config/server";
+const AUTH_ENDPOINT: &'static str = "/a/config/server";
 
 pub struct Config {
     call: call::Call,
@@ -37,6 +40,130 @@ impl Config {
         }
     }
 
+    /// api function 'GET /config/server/info'
+    ///
+    /// returns the information about the gerrit server configuration
+    pub fn get_server_info(&self) -> GGRResult<entities::ServerInfo> {
+        let path = format!("{}/info", ENDPOINT);
+
+        self.call.execute::<(),entities::ServerInfo>("get server info", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /config/server/caches/'
+    ///
+    /// returns a map of cache names to `CacheInfo` entities
+    pub fn list_caches(&self) -> GGRResult<HashMap<String, entities::CacheInfo>> {
+        let path = format!("{}/caches/", AUTH_ENDPOINT);
+
+        self.call.execute::<(),HashMap<String, entities::CacheInfo>>("list caches", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /config/server/caches/'
+    ///
+    /// flushes all caches, or with `caches` only the listed caches
+    pub fn flush_caches(&self, caches: Option<Vec<&str>>) -> GGRResult<()> {
+        let path = format!("{}/caches/", AUTH_ENDPOINT);
+
+        let input = match caches {
+            None => entities::CacheOperationInput {
+                operation: "FLUSH_ALL".into(),
+                caches: None,
+            },
+            Some(caches) => entities::CacheOperationInput {
+                operation: "FLUSH".into(),
+                caches: Some(caches.iter().map(|c| c.to_string()).collect()),
+            },
+        };
+
+        self.call.execute::<&entities::CacheOperationInput,()>("flush caches", &path, call::CallMethod::Post, Some(&&input))
+    }
+
+    /// api function 'GET /config/server/caches/{cache-name}'
+    pub fn get_cache(&self, name: &str) -> GGRResult<entities::CacheInfo> {
+        if name.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::CacheNameEmpty));
+        }
+
+        let path = format!("{}/caches/{}", AUTH_ENDPOINT, call::encode_path_segment(name));
+
+        self.call.execute::<(),entities::CacheInfo>("get cache", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /config/server/caches/{cache-name}/flush'
+    pub fn flush_cache(&self, name: &str) -> GGRResult<()> {
+        if name.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::CacheNameEmpty));
+        }
+
+        let path = format!("{}/caches/{}/flush", AUTH_ENDPOINT, call::encode_path_segment(name));
+
+        self.call.execute::<(),()>("flush cache", &path, call::CallMethod::Post, None)
+    }
+
+    /// api function 'GET /config/server/summary'
+    ///
+    /// * `jvm`: include a summary about the JVM
+    /// * `gc`: run the java garbage collection before the summary is computed
+    pub fn get_summary(&mut self, jvm: bool, gc: bool) -> GGRResult<entities::SummaryInfo> {
+        let mut query = Vec::new();
+        if jvm {
+            query.push("jvm");
+        }
+        if gc {
+            query.push("gc");
+        }
+
+        let querystring = query.join("&");
+        self.call.set_url_query(if querystring.is_empty() { None } else { Some(&querystring) });
+
+        let path = format!("{}/summary", AUTH_ENDPOINT);
+
+        self.call.execute::<(),entities::SummaryInfo>("get summary", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /config/server/capabilities'
+    ///
+    /// returns a map of capability ids to `ConfigCapabilityInfo` entities of all capabilities of
+    /// the server, including capabilities of plugins.
+    pub fn list_capabilities(&self) -> GGRResult<HashMap<String, entities::ConfigCapabilityInfo>> {
+        let path = format!("{}/capabilities", AUTH_ENDPOINT);
+
+        self.call.execute::<(),HashMap<String, entities::ConfigCapabilityInfo>>("list capabilities", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /config/server/tasks/'
+    ///
+    /// returns the tasks from the background work queues that the calling user may see
+    pub fn list_tasks(&self) -> GGRResult<Vec<entities::TaskInfo>> {
+        let path = format!("{}/tasks/", AUTH_ENDPOINT);
+
+        self.call.execute::<(),Vec<entities::TaskInfo>>("list tasks", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /config/server/tasks/{task-id}'
+    pub fn get_task(&self, taskid: &str) -> GGRResult<entities::TaskInfo> {
+        if taskid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::TaskIDEmpty));
+        }
+
+        let path = format!("{}/tasks/{}", AUTH_ENDPOINT, taskid);
+
+        self.call.execute::<(),entities::TaskInfo>("get task", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'DELETE /config/server/tasks/{task-id}'
+    ///
+    /// kills a task from the background work queue
+    pub fn delete_task(&self, taskid: &str) -> GGRResult<()> {
+        if taskid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::TaskIDEmpty));
+        }
+
+        let path = format!("{}/tasks/{}", AUTH_ENDPOINT, taskid);
+
+        self.call.execute::<(),()>("delete task", &path, call::CallMethod::Delete, None)
+    }
+
     /// convenience function to check version
     pub fn check_version(&self, desc: String, since: String) -> GGRResult<()> {
         if let Ok(version) = self.get_version() {
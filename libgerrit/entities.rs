@@ -35,7 +35,174 @@ pub struct AccountInfo {
     /// Whether the query would deliver more results if not limited. Only set on the last account
     /// that is returned.
     /// V02.13
-    pub _more_accounts: Option<String>,
+    pub _more_accounts: Option<bool>,
+}
+
+/// The `AccountNameInput` entity contains information for setting a name for an account.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AccountNameInput {
+    /// The new full name of the account.
+    /// If not set or if set to an empty string, the account name is deleted.
+    /// V02.09
+    pub name: Option<String>,
+}
+
+/// The `UsernameInput` entity contains information for setting the username for an account.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UsernameInput {
+    /// The new username of the account.
+    /// V02.09
+    pub username: String,
+}
+
+/// The `EmailInfo` entity contains information about an email address of a user.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EmailInfo {
+    /// The email address.
+    /// V02.09
+    pub email: String,
+    /// Whether this is the preferred email address of the user. not set if false
+    /// V02.09
+    pub preferred: Option<bool>,
+    /// Set true if the user must confirm control of the email address by following a verification
+    /// link before Gerrit will permit use of this address. not set if false
+    /// V02.09
+    pub pending_confirmation: Option<bool>,
+}
+
+/// The `EmailInput` entity contains information for registering a new email address.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct EmailInput {
+    /// The email address. If provided, must match the email address from the URL.
+    /// V02.09
+    pub email: Option<String>,
+    /// Whether the new email address should become the preferred email address of the user (only
+    /// supported if no_confirmation is set or if the authentication type is DEVELOPMENT_BECOME_ANY_ACCOUNT).
+    /// V02.09
+    pub preferred: Option<bool>,
+    /// Whether the email address should be added without confirmation. In this case no
+    /// verification email is sent to the user.
+    /// Only Gerrit administrators are allowed to add email addresses without confirmation.
+    /// V02.09
+    pub no_confirmation: Option<bool>,
+}
+
+/// The `SshKeyInfo` entity contains information about an SSH key of a user.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SshKeyInfo {
+    /// The sequence number of the SSH key.
+    /// V02.09
+    pub seq: u64,
+    /// The complete public SSH key.
+    /// V02.09
+    pub ssh_public_key: String,
+    /// The encoded key.
+    /// V02.09
+    pub encoded_key: String,
+    /// The algorithm of the SSH key.
+    /// V02.09
+    pub algorithm: String,
+    /// The comment of the SSH key.
+    /// V02.09
+    pub comment: Option<String>,
+    /// Whether the SSH key is valid.
+    /// V02.09
+    pub valid: bool,
+}
+
+/// The `GpgKeysInput` entity contains information for adding/deleting GPG keys.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct GpgKeysInput {
+    /// List of ASCII armored public key strings to add.
+    /// V02.13
+    pub add: Option<Vec<String>>,
+    /// List of key IDs to delete.
+    /// V02.13
+    pub delete: Option<Vec<String>>,
+}
+
+/// The `QueryLimitInfo` entity contains information about the Query Limit of a user.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QueryLimitInfo {
+    /// Lower limit.
+    pub min: i64,
+    /// Upper limit.
+    pub max: i64,
+}
+
+/// The `StarsInput` entity contains star labels that should be added to or removed from a change.
+/// V02.13
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct StarsInput {
+    /// List of labels to add to the change.
+    pub add: Option<Vec<String>>,
+    /// List of labels to remove from the change.
+    pub remove: Option<Vec<String>>,
+}
+
+/// The `CapabilityInfo` entity contains information about the global capabilities of a user.
+///
+/// All boolean capabilities are not set if false.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CapabilityInfo {
+    /// Whether the user has the Access Database capability.
+    #[serde(rename="accessDatabase")]
+    pub access_database: Option<bool>,
+    /// Whether the user has the Administrate Server capability.
+    #[serde(rename="administrateServer")]
+    pub administrate_server: Option<bool>,
+    /// Whether the user has the Create Account capability.
+    #[serde(rename="createAccount")]
+    pub create_account: Option<bool>,
+    /// Whether the user has the Create Group capability.
+    #[serde(rename="createGroup")]
+    pub create_group: Option<bool>,
+    /// Whether the user has the Create Project capability.
+    #[serde(rename="createProject")]
+    pub create_project: Option<bool>,
+    /// Whether the user has the Email Reviewers capability.
+    #[serde(rename="emailReviewers")]
+    pub email_reviewers: Option<bool>,
+    /// Whether the user has the Flush Caches capability.
+    #[serde(rename="flushCaches")]
+    pub flush_caches: Option<bool>,
+    /// Whether the user has the Kill Task capability.
+    #[serde(rename="killTask")]
+    pub kill_task: Option<bool>,
+    /// Whether the user has the Maintain Server capability.
+    /// V02.13
+    #[serde(rename="maintainServer")]
+    pub maintain_server: Option<bool>,
+    /// The name of the thread pool used by the user, see Priority capability.
+    pub priority: Option<String>,
+    /// The Query Limit of the user as `QueryLimitInfo`.
+    #[serde(rename="queryLimit")]
+    pub query_limit: Option<QueryLimitInfo>,
+    /// Whether the user has the Run As capability.
+    #[serde(rename="runAs")]
+    pub run_as: Option<bool>,
+    /// Whether the user has the Run Garbage Collection capability.
+    #[serde(rename="runGC")]
+    pub run_gc: Option<bool>,
+    /// Whether the user has the Stream Events capability.
+    #[serde(rename="streamEvents")]
+    pub stream_events: Option<bool>,
+    /// Whether the user has the View All Accounts capability.
+    /// V02.13
+    #[serde(rename="viewAllAccounts")]
+    pub view_all_accounts: Option<bool>,
+    /// Whether the user has the View Caches capability.
+    #[serde(rename="viewCaches")]
+    pub view_caches: Option<bool>,
+    /// Whether the user has the View Connections capability.
+    #[serde(rename="viewConnections")]
+    pub view_connections: Option<bool>,
+    /// Whether the user has the View Plugins capability.
+    #[serde(rename="viewPlugins")]
+    pub view_plugins: Option<bool>,
+    /// Whether the user has the View Queue capability.
+    #[serde(rename="viewQueue")]
+    pub view_queue: Option<bool>,
 }
 
 /// The `ActionInfo` entity describes a REST API call the client can make to manipulate a resource.
@@ -84,6 +251,37 @@ pub struct LabelInfo {
     /// Whether the label is optional. Optional means the label may be set, but it’s neither
     /// necessary for submission nor does it block submission if set.
     pub optional: Option<bool>,
+    /// One user who approved this label on the change (voted the maximum value) as an AccountInfo
+    /// entity.
+    /// Only set for `LABELS`.
+    pub approved: Option<AccountInfo>,
+    /// One user who rejected this label on the change (voted the minimum value) as an AccountInfo
+    /// entity.
+    /// Only set for `LABELS`.
+    pub rejected: Option<AccountInfo>,
+    /// One user who recommended this label on the change (voted positively, but not the maximum
+    /// value) as an AccountInfo entity.
+    /// Only set for `LABELS`.
+    pub recommended: Option<AccountInfo>,
+    /// One user who disliked this label on the change (voted negatively, but not the minimum
+    /// value) as an AccountInfo entity.
+    /// Only set for `LABELS`.
+    pub disliked: Option<AccountInfo>,
+    /// If true, the label blocks submit operation. If not set, the default is false.
+    /// Only set for `LABELS`.
+    pub blocking: Option<bool>,
+    /// The voting value of the user who recommended/disliked this label on the change if it is not
+    /// "+1"/"-1".
+    /// Only set for `LABELS`.
+    pub value: Option<i8>,
+    /// The default voting value for the label. This value may be outside the range specified in
+    /// permitted_labels.
+    /// Only set for `LABELS`.
+    pub default_value: Option<i8>,
+    /// A map of all values that are allowed for this label. The map maps the values ("-2", "-1", "
+    /// 0", "+1", "+2") to the value descriptions.
+    /// Only set for `DETAILED_LABELS`.
+    pub values: Option<HashMap<String, String>>,
 }
 
 /// The `ChangeMessageInfo` entity contains information about a message attached to a change.
@@ -147,6 +345,22 @@ impl FetchInfo {
     pub fn get_reference_string(&self) -> &str {
         &self.reference.trim_left_matches("refs/changes/")[3..]
     }
+
+    /// compute the reference of a patch set
+    ///
+    /// The reference is build from the change number and the patch set number, like a gerrit
+    /// server without download-commands plugin does it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use libgerrit::entities::FetchInfo;
+    /// assert_eq!("refs/changes/85/225285/1", FetchInfo::change_reference(225285, 1));
+    /// assert_eq!("refs/changes/07/7/2", FetchInfo::change_reference(7, 2));
+    /// ```
+    pub fn change_reference(change_number: u64, patchset_number: u64) -> String {
+        format!("refs/changes/{:02}/{}/{}", change_number % 100, change_number, patchset_number)
+    }
 }
 
 /// The `GitPersonInfo` entity contains information about the author/committer of a commit.
@@ -195,8 +409,44 @@ pub struct CommitInfo {
     pub web_links: Option<String>,
 }
 
-/// The `FileInfo` entity contains information about a file in a patch set.
+/// The `RelatedChangeAndCommitInfo` entity contains information about a related change and
+/// commit.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RelatedChangeAndCommitInfo {
+    /// The project of the change or commit.
+    /// V02.16
+    pub project: Option<String>,
+    /// The Change-Id of the change.
+    /// V02.09
+    pub change_id: Option<String>,
+    /// The commit as a CommitInfo entity.
+    /// V02.09
+    pub commit: CommitInfo,
+    /// The change number.
+    /// V02.09
+    pub _change_number: Option<u64>,
+    /// The revision number.
+    /// V02.09
+    pub _revision_number: Option<u64>,
+    /// The current revision number.
+    /// V02.09
+    pub _current_revision_number: Option<u64>,
+    /// The status of the change. The status of the change is one of (NEW, MERGED, ABANDONED).
+    /// V02.14
+    pub status: Option<String>,
+}
+
+/// The `RelatedChangesInfo` entity contains information about related changes.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RelatedChangesInfo {
+    /// A list of RelatedChangeAndCommitInfo entities describing the related changes. Sorted by git
+    /// commit order, newest to oldest. Empty if there are no related changes.
+    /// V02.09
+    pub changes: Vec<RelatedChangeAndCommitInfo>,
+}
+
+/// The `FileInfo` entity contains information about a file in a patch set.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct FileInfo {
     /// The status of the file ("A"=Added, "D"=Deleted, "R"=Renamed, "C"=Copied, "W"=Rewritten).
     /// Not set if the file was Modified ("M").
@@ -216,15 +466,101 @@ pub struct FileInfo {
     /// Number of deleted lines.
     /// Not set for binary files or if no lines were deleted.
     /// V02.09
-    pub lines_deleted: Option<String>,
+    pub lines_deleted: Option<u64>,
     /// Number of bytes by which the file size increased/decreased.
     /// V02.13
-    pub size_delta: Option<u64>,
+    pub size_delta: Option<i64>,
     /// File size in bytes.
     /// V02.13
     pub size: Option<u64>,
 }
 
+/// The `DiffFileMetaInfo` entity contains meta information about a file diff.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DiffFileMetaInfo {
+    /// The name of the file.
+    pub name: String,
+    /// The content type of the file.
+    pub content_type: String,
+    /// The total number of lines in the file.
+    pub lines: u64,
+    /// Links to the file in external sites as a list of WebLinkInfo entries.
+    pub web_links: Option<Vec<WebLinkInfo>>,
+}
+
+/// The `DiffContent` entity contains information about the content differences in a file.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DiffContent {
+    /// Content only in the file on side A (deleted in B).
+    pub a: Option<Vec<String>>,
+    /// Content only in the file on side B (added in B).
+    pub b: Option<Vec<String>>,
+    /// Content in the file on both sides (unchanged).
+    pub ab: Option<Vec<String>>,
+    /// Text sections deleted from side A as pairs of skip and mark length.
+    /// Only present during a replace, i.e. both a and b are present.
+    pub edit_a: Option<Vec<(u64, u64)>>,
+    /// Text sections inserted in side B as pairs of skip and mark length.
+    /// Only present during a replace, i.e. both a and b are present.
+    pub edit_b: Option<Vec<(u64, u64)>>,
+    /// Indicates whether this entry was introduced by a rebase.
+    /// V02.14
+    pub due_to_rebase: Option<bool>,
+    /// count of lines skipped on both sides when the file is too large to include all common
+    /// lines.
+    pub skip: Option<u64>,
+    /// Set to true if the region is common according to the requested ignore-whitespace parameter,
+    /// but a and b contain differing amounts of whitespace. When present and true a and b are used
+    /// instead of ab.
+    pub common: Option<bool>,
+}
+
+/// The `DiffInfo` entity contains information about the diff of a file in a revision.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DiffInfo {
+    /// Meta information about the file on side A as a DiffFileMetaInfo entity.
+    /// Not set when change_type is ADDED.
+    pub meta_a: Option<DiffFileMetaInfo>,
+    /// Meta information about the file on side B as a DiffFileMetaInfo entity.
+    /// Not set when change_type is DELETED.
+    pub meta_b: Option<DiffFileMetaInfo>,
+    /// The type of change (ADDED, MODIFIED, DELETED, RENAMED COPIED, REWRITE).
+    pub change_type: String,
+    /// Intraline status (OK, ERROR, TIMEOUT). Only set when the intraline parameter was specified
+    /// in the request.
+    pub intraline_status: Option<String>,
+    /// A list of strings representing the patch set diff header.
+    pub diff_header: Vec<String>,
+    /// The content differences in the file as a list of DiffContent entities.
+    pub content: Vec<DiffContent>,
+    /// Whether the file is binary.
+    pub binary: Option<bool>,
+}
+
+/// The `RangeInfo` entity stores the coordinates of a range.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RangeInfo {
+    /// First index.
+    pub start: u64,
+    /// Last index.
+    pub end: u64,
+}
+
+/// The `BlameInfo` entity stores the commit metadata with the row coordinates where it applies.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BlameInfo {
+    /// The author of the commit.
+    pub author: String,
+    /// The id of the commit.
+    pub id: String,
+    /// Commit time.
+    pub time: u64,
+    /// The commit message.
+    pub commit_msg: String,
+    /// The blame row coordinates as RangeInfo entities.
+    pub ranges: Vec<RangeInfo>,
+}
+
 /// V02.13
 #[allow(non_camel_case_types)]
 #[allow(missing_docs)]
@@ -264,7 +600,9 @@ pub struct RevisionInfo {
     pub created: Option<String>,
     /// Information about how to fetch this patch set. The fetch information is provided as a map
     /// that maps the protocol name ("git", "http", "ssh") to FetchInfo entities.
+    /// Empty or missing without the download-commands plugin.
     /// V02.09
+    #[serde(default)]
     pub fetch: HashMap<String, FetchInfo>,
     /// The uploader of the patch set as an AccountInfo entity.
     /// V02.13
@@ -321,9 +659,9 @@ pub struct GpgKeyInfo {
     /// The 40-char (plus spaces) hex GPG key fingerprint.
     pub fingerprint: Option<String>,
     /// OpenPGP User IDs associated with the public key.
-    pub user_ids: String,
+    pub user_ids: Option<Vec<String>>,
     /// ASCII armored public key material.
-    pub key: String,
+    pub key: Option<String>,
     /// The result of server-side checks on the key; one of BAD, OK, or TRUSTED. BAD keys have
     /// serious problems and should not be used. If a key is OK, inspecting only that key found no
     /// problems, but the system does not fully trust the key’s origin. A `TRUSTED key is valid,
@@ -331,7 +669,7 @@ pub struct GpgKeyInfo {
     pub status: Option<String>,
     /// A list of human-readable problem strings found in the course of checking whether the key is
     /// valid and trusted.
-    pub problems: Option<String>,
+    pub problems: Option<Vec<String>>,
 }
 
 
@@ -348,6 +686,31 @@ pub struct ProblemInfo {
     pub outcome: Option<String>,
 }
 
+/// The `FixInput` entity contains options for fixing commits using the fix change endpoint.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct FixInput {
+    /// If true, delete patch sets from the database if they refer to missing commit options.
+    pub delete_patch_set_if_commit_missing: Option<bool>,
+    /// If set, check that the change is merged into the destination branch as this exact SHA-1. If
+    /// not, insert a new patch set referring to this commit.
+    pub expect_merged_as: Option<String>,
+}
+
+/// The `IncludedInInfo` entity contains information about the branches a change was merged into
+/// and tags it was tagged with.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IncludedInInfo {
+    /// The list of branches this change was merged into. Each branch is listed without the
+    /// 'refs/head/' prefix.
+    pub branches: Vec<String>,
+    /// The list of tags this change was tagged with. Each tag is listed without the 'refs/tags/'
+    /// prefix.
+    pub tags: Vec<String>,
+    /// A map that maps a name to a list of external systems that include this change, e.g. a list
+    /// of servers on which this change is deployed.
+    pub external: Option<HashMap<String, Vec<String>>>,
+}
+
 /// `ProjectInfo` helper variant to present a status of a project
 #[allow(non_camel_case_types)]
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -355,6 +718,7 @@ pub enum ProjectInfo_ProjectState {
     /// The project is active
     ACTIVE,
     /// Project is read only, noch anges possible
+    #[serde(rename="READ_ONLY")]
     READONLY,
     /// project is hidden
     HIDDEN,
@@ -393,6 +757,308 @@ pub struct ProjectInfo {
     pub web_links: Option<Vec<WebLinkInfo>>,
 }
 
+/// The `ProjectInput` entity contains information for the creation of a new project.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ProjectInput {
+    /// The name of the project (not encoded).
+    /// If set, must match the project name in the URL.
+    /// If name ends with .git the suffix will be automatically removed.
+    pub name: Option<String>,
+    /// The name of the parent project.
+    /// If not set, the All-Projects project will be the parent project.
+    pub parent: Option<String>,
+    /// The description of the project.
+    pub description: Option<String>,
+    /// Whether a permission-only project should be created.
+    pub permissions_only: Option<bool>,
+    /// Whether an empty initial commit should be created.
+    pub create_empty_commit: Option<bool>,
+    /// The submit type that should be set for the project (MERGE_IF_NECESSARY, REBASE_IF_NECESSARY,
+    /// FAST_FORWARD_ONLY, MERGE_ALWAYS, CHERRY_PICK).
+    /// If not set, MERGE_IF_NECESSARY is set as submit type.
+    pub submit_type: Option<String>,
+    /// A list of branches that should be initially created.
+    /// For the branch names the refs/heads/ prefix can be omitted.
+    pub branches: Option<Vec<String>>,
+    /// A list of groups that should be assigned as project owner.
+    /// Each group in the list must be specified as group-id.
+    /// If not set, the project owner group is set.
+    pub owners: Option<Vec<String>>,
+    /// Whether empty commits should be rejected when a change is merged (TRUE, FALSE, INHERIT).
+    pub reject_empty_commit: Option<String>,
+}
+
+/// The `ProjectParentInput` entity contains information for setting a project parent.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectParentInput {
+    /// The name of the parent project.
+    pub parent: String,
+    /// Message that should be used to commit the change of the project parent in the
+    /// refs/meta/config branch.
+    pub commit_message: Option<String>,
+}
+
+/// The `HeadInput` entity contains information for setting HEAD for a project.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct HeadInput {
+    /// The ref to which HEAD should be set, the refs/heads prefix can be omitted.
+    #[serde(rename="ref")] // "ref" is a keyword
+    pub reference: String,
+}
+
+/// The `BranchInfo` entity contains information about a branch.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BranchInfo {
+    /// The ref of the branch.
+    #[serde(rename="ref")] // "ref" is a keyword
+    pub reference: String,
+    /// The revision to which the branch points.
+    pub revision: String,
+    /// Whether the calling user can delete this branch. not set if false
+    pub can_delete: Option<bool>,
+    /// Links to the branch in external sites as a list of WebLinkInfo entries.
+    pub web_links: Option<Vec<WebLinkInfo>>,
+}
+
+/// The `BranchInput` entity contains information for the creation of a new branch.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct BranchInput {
+    /// The name of the branch. The prefix refs/heads/ can be omitted.
+    /// If set, must match the branch ID in the URL.
+    #[serde(rename="ref")] // "ref" is a keyword
+    pub reference: Option<String>,
+    /// The base revision of the new branch.
+    /// If not set, HEAD will be used as base revision.
+    pub revision: Option<String>,
+}
+
+/// The `TagInfo` entity contains information about a tag.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TagInfo {
+    /// The ref of the tag.
+    #[serde(rename="ref")] // "ref" is a keyword
+    pub reference: String,
+    /// For lightweight tags, the revision of the commit to which the tag points. For annotated
+    /// tags, the revision of the tag object.
+    pub revision: String,
+    /// Only set for annotated tags. The revision of the object to which the tag points.
+    pub object: Option<String>,
+    /// Only set for annotated tags. The tag message.
+    pub message: Option<String>,
+    /// Only set for annotated tags, if present in the tag. The tagger as a GitPersonInfo entity.
+    pub tagger: Option<GitPersonInfo>,
+}
+
+/// The `TagInput` entity contains information for creating a tag.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct TagInput {
+    /// The name of the tag. The leading refs/tags/ is optional.
+    #[serde(rename="ref")] // "ref" is a keyword
+    pub reference: Option<String>,
+    /// The revision to which the tag should point. If not specified, the project’s HEAD will be
+    /// used.
+    pub revision: Option<String>,
+    /// The tag message. When set, the tag will be created as an annotated tag.
+    pub message: Option<String>,
+}
+
+/// The `ProjectAccessInfo` entity contains information about the access rights for a project.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectAccessInfo {
+    /// The revision of the refs/meta/config branch from which the access rights were loaded.
+    pub revision: String,
+    /// The parent project from which permissions are inherited as a ProjectInfo entity.
+    pub inherits_from: Option<ProjectInfo>,
+    /// The local access rights of the project as a map that maps the refs to AccessSectionInfo
+    /// entities.
+    pub local: HashMap<String, AccessSectionInfo>,
+    /// Whether the calling user owns this project.
+    pub is_owner: Option<bool>,
+    /// The list of refs owned by the calling user.
+    pub owner_of: Option<Vec<String>>,
+    /// Whether the calling user can upload to any ref.
+    pub can_upload: Option<bool>,
+    /// Whether the calling user can add any ref.
+    pub can_add: Option<bool>,
+    /// Whether the calling user can add any tag ref.
+    /// V02.15
+    pub can_add_tags: Option<bool>,
+    /// Whether the calling user can see the refs/meta/config branch of the project.
+    pub config_visible: Option<bool>,
+    /// A map of group UUID to `ProjectAccessGroupInfo` objects, with names and URLs for the
+    /// associated groups.
+    /// V02.15
+    pub groups: Option<HashMap<String, ProjectAccessGroupInfo>>,
+}
+
+/// The `ProjectAccessGroupInfo` entity is the subset of a `GroupInfo` which is returned with
+/// `ProjectAccessInfo`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectAccessGroupInfo {
+    /// The name of the group.
+    pub name: Option<String>,
+    /// URL to information about the group.
+    pub url: Option<String>,
+}
+
+/// The `AccessSectionInfo` describes the access rights that are assigned on a ref.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AccessSectionInfo {
+    /// The permissions assigned on the ref of this access section. The permissions are returned
+    /// as a map that maps the permission names to PermissionInfo entities.
+    pub permissions: HashMap<String, PermissionInfo>,
+}
+
+/// The `PermissionInfo` entity contains information about an assigned permission.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PermissionInfo {
+    /// The name of the label. Not set if it's not a label permission.
+    pub label: Option<String>,
+    /// Whether this permission is assigned exclusively.
+    pub exclusive: Option<bool>,
+    /// The rules assigned for this permission as a map that maps the UUIDs of the groups for which
+    /// the permission are assigned to PermissionRuleInfo entities.
+    pub rules: HashMap<String, PermissionRuleInfo>,
+}
+
+/// `PermissionRuleInfo` helper variant to present the action of a permission rule
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum PermissionRuleInfoAction {
+    /// the permission is granted
+    ALLOW,
+    /// the permission is denied
+    DENY,
+    /// the permission is blocked, also for child projects
+    BLOCK,
+    /// priority rule for interactive users
+    INTERACTIVE,
+    /// priority rule for non-interactive users
+    BATCH,
+}
+
+/// The `PermissionRuleInfo` entity contains information about a permission rule that is assigned
+/// to group.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PermissionRuleInfo {
+    /// The action of this rule.
+    pub action: PermissionRuleInfoAction,
+    /// Whether the force flag is set.
+    pub force: Option<bool>,
+    /// The min value of the permission range.
+    pub min: Option<i64>,
+    /// The max value of the permission range.
+    pub max: Option<i64>,
+}
+
+/// The `GroupOptionsInfo` entity contains options of the group.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GroupOptionsInfo {
+    /// Whether the group is visible to all registered users. not set if false
+    pub visible_to_all: Option<bool>,
+}
+
+/// The `GroupInfo` entity contains information about a group. This can be a Gerrit internal group,
+/// or an external group that is known to Gerrit.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GroupInfo {
+    /// The URL encoded UUID of the group.
+    pub id: String,
+    /// The name of the group. For external groups the name is missing if there is no group
+    /// backend that can resolve the group UUID. E.g. this is the case when a plugin that provided
+    /// a group backend was uninstalled.
+    /// Not set if returned in a map where the group name is used as map key.
+    pub name: Option<String>,
+    /// URL to information about the group. Typically a URL to a web page that permits users to
+    /// apply to join the group, or manage their membership.
+    pub url: Option<String>,
+    /// Options of the group
+    pub options: Option<GroupOptionsInfo>,
+    /// The description of the group. only for internal groups
+    pub description: Option<String>,
+    /// The numeric ID of the group. only for internal groups
+    pub group_id: Option<u64>,
+    /// The name of the owner group. only for internal groups
+    pub owner: Option<String>,
+    /// The URL encoded UUID of the owner group. only for internal groups
+    pub owner_id: Option<String>,
+    /// Whether the query would deliver more results if not limited. Only set on the last group
+    /// that is returned.
+    /// V02.13
+    pub _more_groups: Option<bool>,
+    /// A list of AccountInfo entities describing the direct members. Only set if members are
+    /// requested.
+    pub members: Option<Vec<AccountInfo>>,
+    /// A list of GroupInfo entities describing the direct subgroups. Only set if included groups
+    /// are requested.
+    pub includes: Option<Vec<GroupInfo>>,
+}
+
+/// The `GroupBaseInfo` entity contains base information about the group.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GroupBaseInfo {
+    /// The UUID of the group.
+    pub id: String,
+    /// The name of the group.
+    pub name: String,
+}
+
+/// The `SuggestedReviewerInfo` entity contains information about a reviewer that can be added to
+/// a change (an account or a group).
+///
+/// `SuggestedReviewerInfo` has either the account field that contains the AccountInfo entity, or
+/// the group field that contains the GroupBaseInfo entity.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SuggestedReviewerInfo {
+    /// An AccountInfo entity, if the suggestion is an account.
+    /// V02.09
+    pub account: Option<AccountInfo>,
+    /// A GroupBaseInfo entity, if the suggestion is a group.
+    /// V02.09
+    pub group: Option<GroupBaseInfo>,
+    /// The total number of accounts in the suggestion. This is 1 if account is present. If group
+    /// is present, the total number of accounts that are members of the group is returned (this
+    /// count includes members of nested groups).
+    /// V02.13
+    pub count: Option<u64>,
+    /// True if group is present and count is above the threshold where the confirmed flag must be
+    /// passed to add the group as a reviewer.
+    /// V02.13
+    pub confirm: Option<bool>,
+}
+
+/// The `GroupInput` entity contains information for the creation of a new internal group.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct GroupInput {
+    /// The name of the group (not encoded).
+    /// If set, must match the group name in the URL.
+    pub name: Option<String>,
+    /// The description of the group.
+    pub description: Option<String>,
+    /// Whether the group is visible to all registered users.
+    /// If not set, the default is false.
+    pub visible_to_all: Option<bool>,
+    /// The URL encoded ID of the owner group.
+    /// This can be a group UUID, a legacy numeric group ID or a unique group name.
+    /// If not set, the new group will be self-owned.
+    pub owner_id: Option<String>,
+}
+
+/// The `MembersInput` entity contains information about accounts that should be added as members
+/// to a group or that should be deleted from the group.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct MembersInput {
+    /// A list of account ids that identify the accounts that should be added or deleted.
+    pub members: Vec<String>,
+}
+
+/// The `GroupsInput` entity contains information about groups that should be included into a group
+/// or that should be deleted from a group.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct GroupsInput {
+    /// A list of group IDs that identify the groups that should be included or deleted.
+    pub groups: Vec<String>,
+}
+
 /// The `ReviewerUpdateInfo` entity contains information about updates to change’s reviewers set.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ReviewerUpdateInfo {
@@ -507,7 +1173,7 @@ pub struct ChangeInfo {
     /// The labels of the change as a map that maps the label names to LabelInfo entries.
     /// Only set if labels or detailed labels are requested.
     /// V02.09
-    pub labels: Option<LabelInfo>,
+    pub labels: Option<HashMap<String, LabelInfo>>,
     /// A map of the permitted labels that maps a label name to the list of values that are allowed
     /// for that label.
     /// Only set if detailed labels are requested.
@@ -561,6 +1227,15 @@ pub struct ChangeInfo {
     /// Not documented
     /// V02.13
     pub submittable: Option<bool>,
+    /// When present, change is marked as private.
+    /// V02.15
+    pub is_private: Option<bool>,
+    /// When present, change is marked as Work In Progress.
+    /// V02.15
+    pub work_in_progress: Option<bool>,
+    /// When present, change has been marked Ready at some point in time.
+    /// V02.15
+    pub has_review_started: Option<bool>,
 }
 
 /// The `ChangeInput` entity contains information about creating a new change.
@@ -626,7 +1301,7 @@ pub struct ReviewerInfo {
     /// Whether the query would deliver more results if not limited. Only set on the last account
     /// that is returned.
     /// V02.13
-    pub _more_accounts: Option<String>,
+    pub _more_accounts: Option<bool>,
     /// gerritcodereview#reviewer
     /// V02.09
     kind: Option<String>,
@@ -677,13 +1352,78 @@ pub struct ReviewerInput {
     /// reviewer that has many members.
     /// V02.09
     pub confirmed: Option<bool>,
+    /// Notify handling that defines to whom email notifications should be sent after the reviewer
+    /// is added.
+    /// Allowed values are NONE, OWNER, OWNER_REVIEWERS and ALL.
+    /// If not set, the default is ALL.
+    /// V02.13
+    pub notify: Option<NotifyHandling>,
+    /// Additional information about whom to notify about the update as a map of recipient type to
+    /// NotifyInfo entity.
+    /// V02.14
+    pub notify_details: Option<HashMap<RecipientType, NotifyInfo>>,
+}
+
+/// Recipient type of a email notification
+/// V02.14
+#[allow(non_camel_case_types)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RecipientType {
+    /// recipient in the `To` header
+    TO,
+    /// recipient in the `Cc` header
+    CC,
+    /// recipient in the `Bcc` header
+    BCC,
+}
+
+/// The `NotifyInfo` entity contains detailed information about who should be notified about an
+/// update.
+/// V02.14
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct NotifyInfo {
+    /// A list of account IDs that identify the accounts that should be should be notified.
+    pub accounts: Option<Vec<String>>,
+}
+
+/// The `DeleteVoteInput` entity contains options for the deletion of a vote.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DeleteVoteInput {
+    /// The label for which the vote should be deleted.
+    /// If set, must match the label in the URL.
+    /// V02.12
+    pub label: Option<String>,
+    /// Notify handling that defines to whom email notifications should be sent after the vote is
+    /// deleted.
+    /// Allowed values are NONE, OWNER, OWNER_REVIEWERS and ALL.
+    /// If not set, the default is ALL.
+    /// V02.12
+    pub notify: Option<NotifyHandling>,
 }
 
 /// Abandon notifications to ...
 /// V02.13
-#[allow(non_camel_case_types)]
+pub type AbandonInputNotify = NotifyHandling;
+
+/// The `AbandonInput` entity contains information for abandoning a change
 #[derive(Deserialize, Serialize, Debug)]
-pub enum AbandonInputNotify {
+pub struct AbandonInput {
+    /// Message to be added as review comment to the change when abandoning the change.
+    /// V02.09
+    pub message: Option<String>,
+    /// Notify handling that defines to whom email notifications should be sent after the change is
+    /// abandoned.
+    /// Allowed values are NONE, OWNER, OWNER_REVIEWERS and ALL.
+    /// If not set, the default is ALL.
+    /// V02.13
+    pub notify: Option<NotifyHandling>,
+}
+
+/// Notify handling that defines to whom email notifications should be sent after an action.
+/// V02.13
+#[allow(non_camel_case_types)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum NotifyHandling {
     /// Noone
     NONE,
     /// only owner
@@ -694,18 +1434,105 @@ pub enum AbandonInputNotify {
     ALL,
 }
 
-/// The `AbandonInput` entity contains information for abandoning a change
+/// The `SubmitInput` entity contains information for submitting a change.
 #[derive(Deserialize, Serialize, Debug)]
-pub struct AbandonInput {
-    /// Message to be added as review comment to the change when abandoning the change.
-    /// V02.09
-    pub message: Option<String>,
+pub struct SubmitInput {
+    /// If set, submit the change on behalf of the given user. The value may take any format
+    /// accepted by the accounts REST API. Using this option requires Submit (On Behalf Of)
+    /// permission on the branch.
+    /// V02.13
+    pub on_behalf_of: Option<String>,
     /// Notify handling that defines to whom email notifications should be sent after the change is
-    /// abandoned.
+    /// submitted.
     /// Allowed values are NONE, OWNER, OWNER_REVIEWERS and ALL.
     /// If not set, the default is ALL.
     /// V02.13
-    pub notify: Option<AbandonInputNotify>,
+    pub notify: Option<NotifyHandling>,
+}
+
+/// The `MergeableInfo` entity contains information about the mergeability of a change.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MergeableInfo {
+    /// Submit type used for this change, can be MERGE_IF_NECESSARY, FAST_FORWARD_ONLY,
+    /// REBASE_IF_NECESSARY, REBASE_ALWAYS, MERGE_ALWAYS or CHERRY_PICK.
+    /// V02.09
+    pub submit_type: String,
+    /// The strategy of the merge, can be recursive, resolve, simple-two-way-in-core, ours or
+    /// theirs.
+    /// V02.15
+    pub strategy: Option<String>,
+    /// true if this change is cleanly mergeable, false otherwise
+    /// V02.09
+    pub mergeable: bool,
+    /// true if this change is already merged, false otherwise
+    /// V02.15
+    pub commit_merged: Option<bool>,
+    /// true if the content of this change is already merged, false otherwise
+    /// V02.15
+    pub content_merged: Option<bool>,
+    /// A list of paths with conflicts
+    /// V02.15
+    pub conflicts: Option<Vec<String>>,
+    /// A list of other branch names where this change could merge cleanly
+    /// V02.09
+    pub mergeable_into: Option<Vec<String>>,
+}
+
+/// The `RuleInput` entity contains information to test a Prolog rule.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RuleInput {
+    /// Prolog code to execute instead of the code in refs/meta/config.
+    /// V02.09
+    pub rule: String,
+    /// When RUN filter rules in the parent projects are called to post-process the results of the
+    /// project specific rule. When SKIP the parent filters are not called, allowing the test to
+    /// return results from the input rule.
+    /// V02.09
+    pub filters: Option<String>,
+}
+
+/// The `SubmitRecord` entity describes results from a submit_rule.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SubmitRecord {
+    /// OK, the change can be submitted. NOT_READY, additional labels are required before submit.
+    /// CLOSED, closed changes cannot be submitted. RULE_ERROR, rule code failed with an error.
+    /// V02.09
+    pub status: String,
+    /// Map of labels that are approved; an AccountInfo identifies the voter chosen by the rule.
+    /// V02.09
+    pub ok: Option<HashMap<String, AccountInfo>>,
+    /// Map of labels that are preventing submit; AccountInfo identifies voter.
+    /// V02.09
+    pub reject: Option<HashMap<String, AccountInfo>>,
+    /// Map of labels that need to be given to submit. The value is currently an empty object.
+    /// V02.09
+    pub need: Option<HashMap<String, AccountInfo>>,
+    /// Map of labels that can be used, but do not affect submit. AccountInfo identifies voter, if
+    /// the label has been applied to the change.
+    /// V02.09
+    pub may: Option<HashMap<String, AccountInfo>>,
+    /// Map of labels that should have been in need but cannot be used by any user because of
+    /// access restrictions. The value is currently an empty object.
+    /// V02.09
+    pub impossible: Option<HashMap<String, AccountInfo>>,
+    /// If status is RULE_ERROR this message provides some text describing the failure of the rule
+    /// predicate.
+    /// V02.09
+    pub error_message: Option<String>,
+}
+
+/// The `SubmittedTogetherInfo` entity contains information about a collection of changes that
+/// would be submitted together.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SubmittedTogetherInfo {
+    /// A list of ChangeInfo entities representing the changes to be submitted together.
+    /// V02.13
+    pub changes: Vec<ChangeInfo>,
+    /// The number of changes to be submitted together that the current user cannot see. (This
+    /// count includes changes that are visible to the current user when their reason for being
+    /// submitted together involves changes the user cannot see.)
+    /// V02.13
+    pub non_visible_changes: Option<u64>,
 }
 
 /// The `RestoreInput` entity contains information for restoring a change.
@@ -715,6 +1542,140 @@ pub struct RestoreInput {
     pub message: Option<String>,
 }
 
+/// The `TopicInput` entity contains information for setting a topic.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct TopicInput {
+    /// The topic. The topic will be deleted if not set.
+    pub topic: Option<String>,
+}
+
+/// The `WorkInProgressInput` entity contains additional information for a change set to
+/// WorkInProgress/ReadyForReview.
+/// V02.15
+#[derive(Deserialize, Serialize, Debug)]
+pub struct WorkInProgressInput {
+    /// Message to be added as a review comment to the change being set WorkInProgress/ReadyForReview.
+    pub message: Option<String>,
+}
+
+/// The `PrivateInput` entity contains information for changing the private flag on a change.
+/// V02.15
+#[derive(Deserialize, Serialize, Debug)]
+pub struct PrivateInput {
+    /// Message describing why the private flag was changed.
+    pub message: Option<String>,
+}
+
+/// The `EditInfo` entity contains information about a change edit.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EditInfo {
+    /// The commit of change edit as CommitInfo entity.
+    pub commit: CommitInfo,
+    /// The patch set number of the patch set the change edit is based on.
+    /// V02.15
+    pub base_patch_set_number: Option<u64>,
+    /// The revision of the patch set the change edit is based on.
+    pub base_revision: String,
+    /// The ref of the change edit.
+    /// V02.15
+    #[serde(rename="ref")] // "ref" is a keyword
+    pub reference: Option<String>,
+    /// Information about how to fetch this patch set. The fetch information is provided as a map
+    /// that maps the protocol name ("git", "http", "ssh") to FetchInfo entities.
+    pub fetch: Option<HashMap<String, FetchInfo>>,
+    /// The files of the change edit as a map that maps the file names to FileInfo entities.
+    pub files: Option<HashMap<String, FileInfo>>,
+}
+
+/// The `ChangeEditInput` entity contains information for restoring a path within change edit.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ChangeEditInput {
+    /// Path to file to restore.
+    pub restore_path: Option<String>,
+    /// Old path to file to rename.
+    pub old_path: Option<String>,
+    /// New path to file to rename.
+    pub new_path: Option<String>,
+}
+
+/// The `ChangeEditMessageInput` entity contains information for changing the commit message within
+/// a change edit.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ChangeEditMessageInput {
+    /// New commit message.
+    pub message: String,
+}
+
+/// The `PublishChangeEditInput` entity contains options for the publishing of change edit.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct PublishChangeEditInput {
+    /// Notify handling that defines to whom email notifications should be sent after the change
+    /// edit is published.
+    /// Allowed values are NONE, OWNER, OWNER_REVIEWERS and ALL.
+    /// If not set, the default is ALL.
+    /// V02.13
+    pub notify: Option<NotifyHandling>,
+}
+
+/// The `HashtagsInput` entity contains information about hashtags to add to, and/or remove from,
+/// a change.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct HashtagsInput {
+    /// The list of hashtags to be added to the change.
+    pub add: Option<Vec<String>>,
+    /// The list of hashtags to be removed from the change.
+    pub remove: Option<Vec<String>>,
+}
+
+/// The `RevertInput` entity contains information for reverting a change.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RevertInput {
+    /// Message to be added as review comment to the change when reverting the change.
+    /// V02.09
+    pub message: Option<String>,
+}
+
+/// The `MoveInput` entity contains information for moving a change to a new branch.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct MoveInput {
+    /// Destination branch
+    /// V02.13
+    pub destination_branch: String,
+    /// A message to be posted in this change’s comments
+    /// V02.13
+    pub message: Option<String>,
+}
+
+/// The `CherryPickInput` entity contains information for cherry-picking a change to a new branch.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CherryPickInput {
+    /// Commit message for the cherry-picked change
+    /// V02.09
+    pub message: Option<String>,
+    /// Destination branch
+    /// V02.09
+    pub destination: String,
+    /// 40-hex digit SHA-1 of the commit which will be the parent commit of the newly created
+    /// change. If set, it must be a merged commit or a change revision on the destination branch.
+    /// V02.15
+    pub base: Option<String>,
+    /// Number of the parent relative to which the cherry-pick should be considered.
+    /// V02.13
+    pub parent: Option<u64>,
+}
+
+/// The `RebaseInput` entity contains information for changing parent when rebasing.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RebaseInput {
+    /// The new parent revision. This can be a ref or a SHA1 to a concrete patchset.
+    /// Alternatively, a change number can be specified, in which case the current patch set is
+    /// inferred.
+    /// Empty string is used for rebasing directly on top of the target branch, which effectively
+    /// breaks dependency towards a parent change.
+    /// V02.09
+    pub base: Option<String>,
+}
+
 /// The `CommentRange` entity describes the range of an inline comment
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct CommentRange {
@@ -729,7 +1690,7 @@ pub struct CommentRange {
 }
 
 /// The `CommentInput` entity contains information for creating an inline comment
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct CommentInput {
     /// Must be gerritcodereview#comment if provided.
     /// V02.09
@@ -771,10 +1732,66 @@ pub struct CommentInput {
     /// link#review-input[ReviewInput]
     /// V02.13
     pub tag: Option<String>,
+    /// Whether or not the comment must be addressed by the user. This value will default to false
+    /// if the comment is an orphan, or the value of the in_reply_to comment if it is supplied.
+    /// V02.14
+    pub unresolved: Option<bool>,
 }
 
-/// The `ReviewInput` entity contains information for adding a review to a revision
+/// The `CommentInfo` entity contains information about an inline comment.
 #[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CommentInfo {
+    /// The patch set number for the comment; only set in contexts where comments may be returned
+    /// for multiple patch sets.
+    /// V02.09
+    pub patch_set: Option<u64>,
+    /// The URL encoded UUID of the comment.
+    /// V02.09
+    pub id: String,
+    /// The path of the file for which the inline comment was done.
+    /// Not set if returned in a map where the key is the file path.
+    /// V02.09
+    pub path: Option<String>,
+    /// The side on which the comment was added.
+    /// Allowed values are REVISION and PARENT.
+    /// If not set, the default is REVISION.
+    /// V02.09
+    pub side: Option<String>,
+    /// The 1-based parent number. Used only for merge commits when side == PARENT.
+    /// V02.15
+    pub parent: Option<u64>,
+    /// The number of the line for which the comment was done.
+    /// If range is set, this equals the end line of the range.
+    /// If neither line nor range is set, it’s a file comment.
+    /// V02.09
+    pub line: Option<u64>,
+    /// The range of the comment as a CommentRange entity.
+    /// V02.09
+    pub range: Option<CommentRange>,
+    /// The URL encoded UUID of the comment to which this comment is a reply.
+    /// V02.09
+    pub in_reply_to: Option<String>,
+    /// The comment message.
+    /// V02.09
+    pub message: Option<String>,
+    /// The timestamp of when this comment was written.
+    /// V02.09
+    pub updated: String,
+    /// The author of the message as an AccountInfo entity.
+    /// Unset for draft comments, assumed to be the calling user.
+    /// V02.09
+    pub author: Option<AccountInfo>,
+    /// Value of the tag field from ReviewInput set while posting the review.
+    /// V02.13
+    pub tag: Option<String>,
+    /// Whether or not the comment must be addressed by the user. The state of resolution of a
+    /// comment thread is stored in the last comment in that thread chronologically.
+    /// V02.14
+    pub unresolved: Option<bool>,
+}
+
+/// The `ReviewInput` entity contains information for adding a review to a revision
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct ReviewInput {
     /// The message to be added as review comment.
     /// V02.09
@@ -786,7 +1803,7 @@ pub struct ReviewInput {
     /// The comments that should be added as a map that maps a file path to a list of CommentInput
     /// entities.
     /// V02.09
-    pub comments: Option<HashMap<String, CommentInput>>,
+    pub comments: Option<HashMap<String, Vec<CommentInput>>>,
     /// Whether all labels are required to be within the user’s permitted ranges based on access
     /// controls.
     /// If true, attempting to use a label not granted to the user will fail the entire modify
@@ -806,7 +1823,7 @@ pub struct ReviewInput {
     /// Allowed values are NONE, OWNER, OWNER_REVIEWERS and ALL.
     /// If not set, the default is ALL.
     /// V02.09
-    pub notify: Option<String>,
+    pub notify: Option<NotifyHandling>,
     /// {account-id} the review should be posted on behalf of. To use this option the caller must
     /// have been granted labelAs-NAME permission for all keys of labels.
     /// V02.09
@@ -819,6 +1836,27 @@ pub struct ReviewInput {
     /// If true, comments with the same content at the same place will be omitted
     /// V02.13
     pub omit_duplicate_comments: Option<bool>,
+    /// A list of ReviewerInput representing reviewers that should be added to the change.
+    /// V02.13
+    pub reviewers: Option<Vec<ReviewerInput>>,
+}
+
+/// The `ReviewResult` entity contains information regarding the updates that were made to a
+/// review.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReviewResult {
+    /// Map of labels to values after the review was posted. Null if any reviewer additions were
+    /// rejected.
+    /// V02.09
+    pub labels: Option<HashMap<String, i8>>,
+    /// Map of account or group identifier to AddReviewerResult representing the outcome of adding
+    /// as a reviewer. Absent if no reviewer additions were requested.
+    /// V02.13
+    pub reviewers: Option<HashMap<String, AddReviewerResult>>,
+    /// If true, the change was moved from WIP to ready for review as a result of this action. Not
+    /// set if false.
+    /// V02.15
+    pub ready: Option<bool>,
 }
 
 /// The `ReviewInfo` entity contains information about a review
@@ -827,3 +1865,392 @@ pub struct ReviewInfo {
     /// The labels of the review as a map that maps the label names to the voting values.
     pub labels: HashMap<String, i8>,
 }
+
+/// The `PluginInfo` entity describes a plugin.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PluginInfo {
+    /// The ID of the plugin.
+    pub id: String,
+    /// The version of the plugin.
+    pub version: String,
+    /// URL of the plugin's default page.
+    pub index_url: Option<String>,
+    /// The plugin's filename.
+    /// V02.15
+    pub filename: Option<String>,
+    /// Whether the plugin is disabled. Not set if false.
+    pub disabled: Option<bool>,
+}
+
+/// The `ServerInfo` entity contains information about the configuration of the Gerrit server.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ServerInfo {
+    /// Information about the configuration from the accounts section.
+    /// V02.15
+    pub accounts: Option<AccountsConfigInfo>,
+    /// Information about the authentication configuration.
+    pub auth: AuthInfo,
+    /// Information about the configuration from the change section.
+    pub change: ChangeConfigInfo,
+    /// Information about the download configuration.
+    pub download: DownloadInfo,
+    /// Information about the configuration from the gerrit section.
+    pub gerrit: GerritInfo,
+    /// Whether the NoteDb storage backend is fully enabled.
+    /// V02.14
+    pub note_db_enabled: Option<bool>,
+    /// Information about Gerrit extensions by plugins.
+    pub plugin: Option<PluginConfigInfo>,
+    /// Information about the receive-pack configuration.
+    /// V02.13
+    pub receive: Option<ReceiveInfo>,
+    /// Set only if the Gerrit SSH daemon is enabled.
+    pub sshd: Option<SshdInfo>,
+    /// Information about the configuration from the suggest section.
+    pub suggest: Option<SuggestInfo>,
+    /// A map of URL aliases.
+    pub url_aliases: Option<HashMap<String, String>>,
+    /// Information about the configuration from the user section.
+    pub user: Option<UserConfigInfo>,
+}
+
+/// The `AccountsConfigInfo` entity contains information about Gerrit configuration from the
+/// accounts section.
+/// V02.15
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AccountsConfigInfo {
+    /// The value of the accounts.visibility parameter.
+    pub visibility: String,
+}
+
+/// The `AuthInfo` entity contains information about the authentication configuration of the
+/// Gerrit server.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AuthInfo {
+    /// The authentication type that is configured on the server.
+    #[serde(rename="type")] // "type" is a keyword
+    pub auth_type: String,
+    /// Whether contributor agreements are required.
+    pub use_contributor_agreements: Option<bool>,
+    /// List of account fields that are editable.
+    pub editable_account_fields: Vec<String>,
+    /// The login URL. Only set if authentication type is HTTP, HTTP_LDAP or OAUTH.
+    pub login_url: Option<String>,
+    /// The login text. Only set if authentication type is HTTP, HTTP_LDAP or OAUTH.
+    pub login_text: Option<String>,
+    /// The URL to switch accounts.
+    pub switch_account_url: Option<String>,
+    /// The register URL. Only set if authentication type is LDAP.
+    pub register_url: Option<String>,
+    /// The register text. Only set if authentication type is LDAP.
+    pub register_text: Option<String>,
+    /// The URL to edit the full name. Only set if authentication type is LDAP.
+    pub edit_full_name_url: Option<String>,
+    /// The URL to obtain an HTTP password. Only set if authentication type is CUSTOM_EXTENSION.
+    pub http_password_url: Option<String>,
+    /// The policy to authenticate Git over HTTP and REST API requests.
+    /// V02.13
+    pub git_basic_auth_policy: Option<String>,
+}
+
+/// The `ChangeConfigInfo` entity contains information about Gerrit configuration from the change
+/// section.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ChangeConfigInfo {
+    /// Whether blame is allowed.
+    /// V02.13
+    pub allow_blame: Option<bool>,
+    /// Whether draft workflow is allowed.
+    pub allow_drafts: Option<bool>,
+    /// Value of number of files in a change from which on the change is considered as a large
+    /// change.
+    pub large_change: u64,
+    /// Label name for the reply button.
+    pub reply_label: String,
+    /// Tooltip for the reply button.
+    pub reply_tooltip: String,
+    /// How often in seconds the web interface should poll for updates to the currently open
+    /// change.
+    pub update_delay: u64,
+    /// Whether to submit all changes in a topic together.
+    pub submit_whole_topic: Option<bool>,
+    /// Whether private changes are disabled.
+    /// V02.15
+    pub disable_private_changes: Option<bool>,
+}
+
+/// The `DownloadInfo` entity contains information about supported download options.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DownloadInfo {
+    /// The supported download schemes as a map which maps the scheme name to a of
+    /// `DownloadSchemeInfo` entity.
+    pub schemes: HashMap<String, DownloadSchemeInfo>,
+    /// List of supported archive formats.
+    pub archives: Vec<String>,
+}
+
+/// The `DownloadSchemeInfo` entity contains information about a supported download scheme and its
+/// commands.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DownloadSchemeInfo {
+    /// The URL of the download scheme, where `${project}` is used as placeholder for the project
+    /// name.
+    pub url: String,
+    /// Whether this download scheme requires authentication.
+    pub is_auth_required: Option<bool>,
+    /// Whether this download scheme supports authentication.
+    pub is_auth_supported: Option<bool>,
+    /// Download commands as a map which maps the command name to the download command.
+    pub commands: HashMap<String, String>,
+    /// Clone commands as a map which maps the command name to the clone command.
+    pub clone_commands: HashMap<String, String>,
+}
+
+/// The `GerritInfo` entity contains information about Gerrit configuration from the gerrit
+/// section.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GerritInfo {
+    /// Name of the root project.
+    pub all_projects: String,
+    /// Name of the project in which meta data of all users is stored.
+    pub all_users: String,
+    /// Whether documentation search is available.
+    pub doc_search: bool,
+    /// Custom base URL where Gerrit server documentation is located.
+    pub doc_url: Option<String>,
+    /// Whether to enable the web UI for editing GPG keys.
+    pub edit_gpg_keys: Option<bool>,
+    /// URL to report bugs.
+    pub report_bug_url: Option<String>,
+    /// Display text for report bugs link.
+    pub report_bug_text: Option<String>,
+}
+
+/// The `PluginConfigInfo` entity contains information about Gerrit extensions by plugins.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PluginConfigInfo {
+    /// Whether an avatar provider is registered.
+    pub has_avatars: Option<bool>,
+    /// List of paths of JavaScript files provided by plugins.
+    /// V02.13
+    pub js_resource_paths: Option<Vec<String>>,
+}
+
+/// The `ReceiveInfo` entity contains information about the configuration of git-receive-pack
+/// behavior on the server.
+/// V02.13
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ReceiveInfo {
+    /// Whether signed push validation support is enabled on the server.
+    pub enable_signed_push: Option<bool>,
+}
+
+/// The `SshdInfo` entity contains information about Gerrit configuration from the sshd section.
+///
+/// This entity doesn't contain any data, but the presence of this (empty) entity in the
+/// `ServerInfo` entity means that SSHD is enabled on the server.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SshdInfo {}
+
+/// The `SuggestInfo` entity contains information about Gerrit configuration from the suggest
+/// section.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SuggestInfo {
+    /// The number of characters that a user must have typed before suggestions are provided.
+    pub from: u64,
+}
+
+/// The `UserConfigInfo` entity contains information about Gerrit configuration from the user
+/// section.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserConfigInfo {
+    /// Display name of a user without name.
+    pub anonymous_coward_name: String,
+}
+
+/// The `CacheInfo` entity contains information about a cache.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CacheInfo {
+    /// The cache name. Not set if returned in a map where the cache name is used as map key.
+    pub name: Option<String>,
+    /// The type of the cache (`MEM`: in memory cache, `DISK`: disk cache).
+    #[serde(rename="type")] // "type" is a keyword
+    pub cache_type: String,
+    /// Information about the entries in the cache.
+    pub entries: EntriesInfo,
+    /// The average duration of getting one entry from the cache.
+    pub average_get: Option<String>,
+    /// Information about the hit ratio.
+    pub hit_ratio: HitRatioInfo,
+}
+
+/// The `EntriesInfo` entity contains information about the entries in a cache.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EntriesInfo {
+    /// Number of cache entries that are held in memory.
+    pub mem: Option<u64>,
+    /// Number of cache entries on the disk. For non-disk caches this value is not set.
+    pub disk: Option<u64>,
+    /// The space that is used by the cache on disk. For non-disk caches this value is not set.
+    pub space: Option<String>,
+}
+
+/// The `HitRatioInfo` entity contains information about the hit ratio of a cache in percent.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct HitRatioInfo {
+    /// The hit ratio for cache entries that are held in memory.
+    pub mem: u64,
+    /// The hit ratio for cache entries that are held on disk. For non-disk caches this value is
+    /// not set.
+    pub disk: Option<u64>,
+}
+
+/// The `CacheOperationInput` entity contains information about an operation that should be
+/// executed on caches.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CacheOperationInput {
+    /// The cache operation that should be executed (`FLUSH_ALL` or `FLUSH`).
+    pub operation: String,
+    /// A list of cache names. Only set for the `FLUSH` operation.
+    pub caches: Option<Vec<String>>,
+}
+
+/// The `SummaryInfo` entity contains information about the current state of the server.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SummaryInfo {
+    /// Summary about current tasks.
+    pub task_summary: TaskSummaryInfo,
+    /// Summary about the current memory usage.
+    pub mem_summary: MemSummaryInfo,
+    /// Summary about the current threads.
+    pub thread_summary: ThreadSummaryInfo,
+    /// Summary about the JVM. Only set if the `jvm` option was set.
+    pub jvm_summary: Option<JvmSummaryInfo>,
+}
+
+/// The `TaskSummaryInfo` entity contains information about the current tasks.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TaskSummaryInfo {
+    /// The total number of current tasks.
+    pub total: Option<u64>,
+    /// The number of tasks which are currently running.
+    pub running: Option<u64>,
+    /// The number of tasks which are currently ready.
+    pub ready: Option<u64>,
+    /// The number of tasks which are currently sleeping.
+    pub sleeping: Option<u64>,
+}
+
+/// The `MemSummaryInfo` entity contains information about the current memory usage.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MemSummaryInfo {
+    /// The total size of the memory.
+    pub total: String,
+    /// The size of used memory.
+    pub used: String,
+    /// The size of free memory.
+    pub free: String,
+    /// The size of memory used for JGit buffers.
+    pub buffers: String,
+    /// The maximal memory size.
+    pub max: String,
+    /// The number of open files.
+    pub open_files: Option<u64>,
+}
+
+/// The `ThreadSummaryInfo` entity contains information about the current threads.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ThreadSummaryInfo {
+    /// The number of available processors.
+    pub cpus: u64,
+    /// The total number of current threads.
+    pub threads: u64,
+    /// Map of thread counts per thread group and thread state.
+    pub counts: HashMap<String, HashMap<String, u64>>,
+}
+
+/// The `JvmSummaryInfo` entity contains information about the JVM.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct JvmSummaryInfo {
+    /// The vendor of the virtual machine.
+    pub vm_vendor: String,
+    /// The name of the virtual machine.
+    pub vm_name: String,
+    /// The version of the virtual machine.
+    pub vm_version: String,
+    /// The name of the operating system.
+    pub os_name: String,
+    /// The version of the operating system.
+    pub os_version: String,
+    /// The architecture of the operating system.
+    pub os_arch: String,
+    /// The user that is running Gerrit.
+    pub user: String,
+    /// The host on which Gerrit is running.
+    pub host: Option<String>,
+    /// The current working directory.
+    pub current_working_directory: String,
+    /// The path to the review site.
+    pub site: String,
+}
+
+/// The `ConfigCapabilityInfo` entity contains information about a capability, as returned by the
+/// config endpoint.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ConfigCapabilityInfo {
+    /// The ID of the capability.
+    pub id: String,
+    /// The name of the capability.
+    pub name: String,
+}
+
+/// `TaskInfo` helper variant to present the state of a task
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum TaskInfoState {
+    /// task is done
+    DONE,
+    /// task is cancelled
+    CANCELLED,
+    /// task is running
+    RUNNING,
+    /// task is ready
+    READY,
+    /// task is sleeping
+    SLEEPING,
+    /// task is in an other state
+    OTHER,
+}
+
+/// The `TaskInfo` entity contains information about a task in a background work queue.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TaskInfo {
+    /// The ID of the task.
+    pub id: String,
+    /// The state of the task.
+    pub state: TaskInfoState,
+    /// The start time of the task.
+    pub start_time: String,
+    /// The remaining delay of the task, negative for running or overdue tasks.
+    pub delay: i64,
+    /// The command of the task.
+    pub command: String,
+    /// The remote name. Only set for replication tasks.
+    pub remote_name: Option<String>,
+    /// The project the task is related to.
+    pub project: Option<String>,
+}
+
+#[test]
+fn test_account_info_more_accounts() {
+    use serde_json;
+
+    let accounts: Vec<AccountInfo> = serde_json::from_str(r#"[
+        {"_account_id": 1000096, "name": "John Doe"},
+        {"_account_id": 1000097, "name": "Jane Roe", "_more_accounts": true}
+    ]"#).unwrap();
+    assert_eq!(accounts[0]._more_accounts, None);
+    assert_eq!(accounts[1]._more_accounts, Some(true));
+
+    let reviewer: ReviewerInfo = serde_json::from_str(r#"{"_account_id": 1000096, "approvals": {}, "_more_accounts": true}"#).unwrap();
+    assert_eq!(reviewer._more_accounts, Some(true));
+}
@@ -1,6 +1,7 @@
 
 //! Implements generic error type
 
+use base64;
 use curl;
 use git2;
 use serde_json;
@@ -43,12 +44,49 @@ quick_error! {
         ChangeIDEmpty {
             description("ChangeID is empty")
         }
+
+        AccountIDEmpty {
+            description("AccountID is empty")
+        }
+
+        ProjectNameEmpty {
+            description("Project name is empty")
+        }
+
+        BranchNameEmpty {
+            description("Branch name is empty")
+        }
+
+        GroupIDEmpty {
+            description("GroupID is empty")
+        }
+
+        CacheNameEmpty {
+            description("Cache name is empty")
+        }
+
+        TaskIDEmpty {
+            description("TaskID is empty")
+        }
+
+        PluginIDEmpty {
+            description("PluginID is empty")
+        }
+
+        UnknownNotify(n: String) {
+            description("unknown notify handling")
+            display("unknown notify handling '{}', allowed are none, owner, owner_reviewers and all", n)
+        }
     }
 }
 
 quick_error! {
     #[derive(Debug)]
     pub enum GGRError {
+        Base64(err: base64::DecodeError) {
+            description(err.description())
+            from()
+        }
         Curl(err: curl::Error) {
             description(err.description())
             from()
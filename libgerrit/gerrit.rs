@@ -1,8 +1,13 @@
 
 //! Implements the gerrit structure
 
+use access;
+use accounts;
 use config;
 use changes;
+use groups;
+use plugins;
+use projects;
 use url;
 
 /// `Gerrit` structure for management of several gerrit endpoints
@@ -24,6 +29,20 @@ impl Gerrit {
         }
     }
 
+    /// Returnes a Access endpoint
+    ///
+    /// list the access rights of projects
+    pub fn access(&mut self) -> access::Access {
+        access::Access::new(&self.url)
+    }
+
+    /// Returnes a Accounts endpoint
+    ///
+    /// query accounts and manage names, emails, ssh/gpg keys of accounts
+    pub fn accounts(&mut self) -> accounts::Accounts {
+        accounts::Accounts::new(&self.url)
+    }
+
     /// Returnes a Change endpoint
     ///
     /// This represent a change endpoint for add, remove or manipulating of changes and changesets
@@ -37,5 +56,26 @@ impl Gerrit {
     pub fn config(&mut self) -> config::Config {
         config::Config::new(&self.url)
     }
+
+    /// Returnes a Groups endpoint
+    ///
+    /// query groups and manage members and included groups of groups
+    pub fn groups(&mut self) -> groups::Groups {
+        groups::Groups::new(&self.url)
+    }
+
+    /// Returnes a Plugins endpoint
+    ///
+    /// list, enable, disable and reload plugins
+    pub fn plugins(&mut self) -> plugins::Plugins {
+        plugins::Plugins::new(&self.url)
+    }
+
+    /// Returnes a Projects endpoint
+    ///
+    /// query projects and manage branches and tags of projects
+    pub fn projects(&mut self) -> projects::Projects {
+        projects::Projects::new(&self.url)
+    }
 }
 
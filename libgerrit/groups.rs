@@ -0,0 +1,192 @@
+
+//! Implements the groups endpoint
+//!
+//! The `{group-id}` can be the UUID of the group, the legacy numeric id or the unique name of the
+//! group.
+
+use call;
+use config;
+use error::GGRError;
+use error::GGRResult;
+use error::GerritError;
+use entities;
+use std::collections::HashMap;
+use url;
+
+const ENDPOINT: &'static str = "/a/groups";
+
+/// Interface to retrieve Groups information from gerrit server
+pub struct Groups {
+    call: call::Call,
+}
+
+impl Groups {
+    /// create a new `Groups` instance
+    pub fn new(url: &url::Url) -> Groups {
+        Groups {
+            call: call::Call::new(url),
+        }
+    }
+
+    /// check `groupid` and returns the endpoint path for this group
+    fn group_path(groupid: &str) -> GGRResult<String> {
+        if groupid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::GroupIDEmpty));
+        }
+
+        Ok(format!("{}/{}", ENDPOINT, call::encode_path_segment(groupid)))
+    }
+
+    /// api function 'GET /groups/'
+    ///
+    /// returns a map of group names to `GroupInfo` entities.
+    ///
+    /// * `user`: only groups which contain this user as member
+    /// * `project`: only groups which are used in the access rights of this project
+    /// * `options`: additional fields like `MEMBERS` or `INCLUDES`
+    /// * `limit`: limit the count of returned groups
+    pub fn list_groups(&mut self, user: Option<&str>, project: Option<&str>, options: Option<Vec<&str>>, limit: Option<usize>) -> GGRResult<HashMap<String, entities::GroupInfo>> {
+        let mut query = Vec::new();
+        if let Some(user) = user {
+            query.push(format!("user={}", user));
+        }
+        if let Some(project) = project {
+            query.push(format!("p={}", project));
+        }
+        for option in options.unwrap_or_default() {
+            query.push(format!("o={}", option));
+        }
+        if let Some(limit) = limit {
+            query.push(format!("n={}", limit));
+        }
+
+        let querystring = query.join("&");
+        self.call.set_url_query(if querystring.is_empty() { None } else { Some(&querystring) });
+
+        let path = format!("{}/", ENDPOINT);
+
+        self.call.execute::<(),HashMap<String, entities::GroupInfo>>("list groups", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /groups/?query='
+    ///
+    /// V02.16
+    pub fn query_groups(&mut self, query: &str, limit: Option<usize>) -> GGRResult<Vec<entities::GroupInfo>> {
+        let config = config::Config::new(self.call.get_base());
+        if let Err(x) = config.check_version("GET /groups/?query=".into(), "2.16.0".into()) {
+            return Err(x);
+        }
+
+        let mut querystring = format!("query={}", query);
+        if let Some(limit) = limit {
+            querystring = format!("{}&n={}", querystring, limit);
+        }
+        self.call.set_url_query(Some(&querystring));
+
+        let path = format!("{}/", ENDPOINT);
+
+        self.call.execute::<(),Vec<entities::GroupInfo>>("query groups", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /groups/{group-id}'
+    pub fn get_group(&self, groupid: &str) -> GGRResult<entities::GroupInfo> {
+        let path = Groups::group_path(groupid)?;
+
+        self.call.execute::<(),entities::GroupInfo>("get group", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /groups/{group-id}/detail'
+    ///
+    /// The returned `GroupInfo` contains the direct members and included groups.
+    pub fn get_group_detail(&self, groupid: &str) -> GGRResult<entities::GroupInfo> {
+        let path = format!("{}/detail", Groups::group_path(groupid)?);
+
+        self.call.execute::<(),entities::GroupInfo>("get group detail", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /groups/{group-name}'
+    pub fn create_group(&self, groupname: &str, groupinput: &entities::GroupInput) -> GGRResult<entities::GroupInfo> {
+        let path = Groups::group_path(groupname)?;
+
+        self.call.execute::<&entities::GroupInput,entities::GroupInfo>("create group", &path, call::CallMethod::Put, Some(&groupinput))
+    }
+
+    /// api function 'GET /groups/{group-id}/members/'
+    ///
+    /// With `recursive` the members of included groups are resolved too.
+    pub fn list_members(&mut self, groupid: &str, recursive: bool) -> GGRResult<Vec<entities::AccountInfo>> {
+        let path = format!("{}/members/", Groups::group_path(groupid)?);
+
+        self.call.set_url_query(if recursive { Some("recursive") } else { None });
+
+        self.call.execute::<(),Vec<entities::AccountInfo>>("list group members", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /groups/{group-id}/members/{account-id}'
+    pub fn add_member(&self, groupid: &str, accountid: &str) -> GGRResult<entities::AccountInfo> {
+        let path = format!("{}/members/{}", Groups::group_path(groupid)?, accountid);
+
+        self.call.execute::<(),entities::AccountInfo>("add group member", &path, call::CallMethod::Put, None)
+    }
+
+    /// api function 'POST /groups/{group-id}/members.add'
+    pub fn add_members(&self, groupid: &str, accountids: Vec<&str>) -> GGRResult<Vec<entities::AccountInfo>> {
+        let path = format!("{}/members.add", Groups::group_path(groupid)?);
+
+        let membersinput = entities::MembersInput {
+            members: accountids.iter().map(|s| s.to_string()).collect(),
+        };
+
+        self.call.execute::<&entities::MembersInput,Vec<entities::AccountInfo>>("add group members", &path, call::CallMethod::Post, Some(&&membersinput))
+    }
+
+    /// api function 'DELETE /groups/{group-id}/members/{account-id}'
+    pub fn remove_member(&self, groupid: &str, accountid: &str) -> GGRResult<()> {
+        let path = format!("{}/members/{}", Groups::group_path(groupid)?, accountid);
+
+        self.call.execute::<(),()>("remove group member", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'POST /groups/{group-id}/members.delete'
+    pub fn remove_members(&self, groupid: &str, accountids: Vec<&str>) -> GGRResult<()> {
+        let path = format!("{}/members.delete", Groups::group_path(groupid)?);
+
+        let membersinput = entities::MembersInput {
+            members: accountids.iter().map(|s| s.to_string()).collect(),
+        };
+
+        self.call.execute::<&entities::MembersInput,()>("remove group members", &path, call::CallMethod::Post, Some(&&membersinput))
+    }
+
+    /// api function 'GET /groups/{group-id}/groups/'
+    pub fn list_included_groups(&self, groupid: &str) -> GGRResult<Vec<entities::GroupInfo>> {
+        let path = format!("{}/groups/", Groups::group_path(groupid)?);
+
+        self.call.execute::<(),Vec<entities::GroupInfo>>("list included groups", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /groups/{group-id}/groups/{group-id}'
+    pub fn include_group(&self, groupid: &str, includegroupid: &str) -> GGRResult<entities::GroupInfo> {
+        let path = format!("{}/groups/{}", Groups::group_path(groupid)?, call::encode_path_segment(includegroupid));
+
+        self.call.execute::<(),entities::GroupInfo>("include group", &path, call::CallMethod::Put, None)
+    }
+
+    /// api function 'POST /groups/{group-id}/groups'
+    pub fn include_groups(&self, groupid: &str, includegroupids: Vec<&str>) -> GGRResult<Vec<entities::GroupInfo>> {
+        let path = format!("{}/groups", Groups::group_path(groupid)?);
+
+        let groupsinput = entities::GroupsInput {
+            groups: includegroupids.iter().map(|s| s.to_string()).collect(),
+        };
+
+        self.call.execute::<&entities::GroupsInput,Vec<entities::GroupInfo>>("include groups", &path, call::CallMethod::Post, Some(&&groupsinput))
+    }
+
+    /// api function 'DELETE /groups/{group-id}/groups/{group-id}'
+    pub fn remove_included_group(&self, groupid: &str, includegroupid: &str) -> GGRResult<()> {
+        let path = format!("{}/groups/{}", Groups::group_path(groupid)?, call::encode_path_segment(includegroupid));
+
+        self.call.execute::<(),()>("remove included group", &path, call::CallMethod::Delete, None)
+    }
+}
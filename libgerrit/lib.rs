@@ -4,6 +4,7 @@
 #[macro_use] extern crate log;
 #[macro_use] extern crate quick_error;
 #[macro_use] extern crate serde_derive;
+extern crate base64;
 extern crate curl;
 extern crate git2;
 extern crate semver;
@@ -11,9 +12,14 @@ extern crate serde;
 extern crate serde_json;
 extern crate url;
 
+pub mod access;
+pub mod accounts;
 pub mod call;
 pub mod changes;
 pub mod config;
 pub mod entities;
 pub mod error;
 pub mod gerrit;
+pub mod groups;
+pub mod plugins;
+pub mod projects;
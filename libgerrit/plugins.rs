@@ -0,0 +1,100 @@
+//! Implements the plugins endpoint
+
+use call;
+use config;
+use error::GGRError;
+use error::GGRResult;
+use error::GerritError;
+use entities;
+use std::collections::HashMap;
+use url;
+
+const ENDPOINT: &'static str = "/a/plugins";
+
+/// name of the plugin which provides the download schemes and fetch commands
+pub const DOWNLOAD_COMMANDS: &'static str = "download-commands";
+
+/// Interface to retrieve Plugins information from gerrit server
+pub struct Plugins {
+    call: call::Call,
+}
+
+impl Plugins {
+    /// create a new `Plugins` instance
+    pub fn new(url: &url::Url) -> Plugins {
+        Plugins {
+            call: call::Call::new(url),
+        }
+    }
+
+    /// check `pluginid` and returns the endpoint path for this plugin
+    fn plugin_path(pluginid: &str) -> GGRResult<String> {
+        if pluginid.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::PluginIDEmpty));
+        }
+
+        Ok(format!("{}/{}", ENDPOINT, call::encode_path_segment(pluginid)))
+    }
+
+    /// api function 'GET /plugins/'
+    ///
+    /// returns a map of plugin ids to `PluginInfo` entities. With `all` disabled plugins are
+    /// included.
+    pub fn list_plugins(&mut self, all: bool) -> GGRResult<HashMap<String, entities::PluginInfo>> {
+        self.call.set_url_query(if all { Some("all") } else { None });
+
+        let path = format!("{}/", ENDPOINT);
+
+        self.call.execute::<(),HashMap<String, entities::PluginInfo>>("list plugins", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /plugins/{plugin-id}/gerrit~status'
+    pub fn get_plugin_status(&self, pluginid: &str) -> GGRResult<entities::PluginInfo> {
+        let path = format!("{}/gerrit~status", Plugins::plugin_path(pluginid)?);
+
+        self.call.execute::<(),entities::PluginInfo>("get plugin status", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'POST /plugins/{plugin-id}/gerrit~enable'
+    pub fn enable_plugin(&self, pluginid: &str) -> GGRResult<entities::PluginInfo> {
+        let path = format!("{}/gerrit~enable", Plugins::plugin_path(pluginid)?);
+
+        self.call.execute::<(),entities::PluginInfo>("enable plugin", &path, call::CallMethod::Post, None)
+    }
+
+    /// api function 'POST /plugins/{plugin-id}/gerrit~disable'
+    pub fn disable_plugin(&self, pluginid: &str) -> GGRResult<entities::PluginInfo> {
+        let path = format!("{}/gerrit~disable", Plugins::plugin_path(pluginid)?);
+
+        self.call.execute::<(),entities::PluginInfo>("disable plugin", &path, call::CallMethod::Post, None)
+    }
+
+    /// api function 'POST /plugins/{plugin-id}/gerrit~reload'
+    pub fn reload_plugin(&self, pluginid: &str) -> GGRResult<entities::PluginInfo> {
+        let path = format!("{}/gerrit~reload", Plugins::plugin_path(pluginid)?);
+
+        self.call.execute::<(),entities::PluginInfo>("reload plugin", &path, call::CallMethod::Post, None)
+    }
+
+    /// convenience function to check for an enabled download-commands plugin
+    ///
+    /// Without this plugin the `fetch` field of `RevisionInfo` entities is empty. Listing plugins
+    /// needs the `View Plugins` capability, without it the download schemes of the server info are
+    /// checked, which are provided by the same plugin. If both is not possible, `true` is
+    /// returned.
+    pub fn has_download_commands(&mut self) -> bool {
+        match self.list_plugins(false) {
+            Ok(plugins) => plugins.get(DOWNLOAD_COMMANDS).map_or(false, |pi| !pi.disabled.unwrap_or(false)),
+            Err(x) => {
+                debug!("list plugins not possible ({}), check download schemes", x);
+                match config::Config::new(self.call.get_base()).get_server_info() {
+                    Ok(si) => !si.download.schemes.is_empty(),
+                    Err(x) => {
+                        warn!("cannot check for {} plugin ({}), continuing", DOWNLOAD_COMMANDS, x);
+                        true
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,226 @@
+
+//! Implements the projects endpoint
+
+use call;
+use config;
+use error::GGRError;
+use error::GGRResult;
+use error::GerritError;
+use entities;
+use std::collections::HashMap;
+use url;
+
+const ENDPOINT: &'static str = "/a/projects";
+
+/// Interface to retrieve Projects information from gerrit server
+pub struct Projects {
+    call: call::Call,
+}
+
+impl Projects {
+    /// create a new `Projects` instance
+    pub fn new(url: &url::Url) -> Projects {
+        Projects {
+            call: call::Call::new(url),
+        }
+    }
+
+    /// check `project` and returns the endpoint path for this project
+    fn project_path(project: &str) -> GGRResult<String> {
+        if project.is_empty() {
+            return Err(GGRError::GerritApiError(GerritError::ProjectNameEmpty));
+        }
+
+        Ok(format!("{}/{}", ENDPOINT, call::encode_path_segment(project)))
+    }
+
+    /// api function 'GET /projects/'
+    ///
+    /// returns a map of project names to `ProjectInfo` entities.
+    ///
+    /// * `prefix`: limit the results to projects starting with prefix
+    /// * `regex`: limit the results to projects matching the regular expression
+    /// * `branches`: add the HEAD revisions of this branches to the `ProjectInfo`
+    /// * `description`: include the project description
+    /// * `limit`: limit the count of returned projects
+    pub fn list_projects(&mut self, prefix: Option<&str>, regex: Option<&str>, branches: Option<Vec<&str>>, description: bool, limit: Option<usize>) -> GGRResult<HashMap<String, entities::ProjectInfo>> {
+        let mut query = Vec::new();
+        if let Some(prefix) = prefix {
+            query.push(format!("p={}", prefix));
+        }
+        if let Some(regex) = regex {
+            query.push(format!("r={}", regex));
+        }
+        for branch in branches.unwrap_or_default() {
+            query.push(format!("b={}", branch));
+        }
+        if description {
+            query.push("d".into());
+        }
+        if let Some(limit) = limit {
+            query.push(format!("n={}", limit));
+        }
+
+        let querystring = query.join("&");
+        self.call.set_url_query(if querystring.is_empty() { None } else { Some(&querystring) });
+
+        let path = format!("{}/", ENDPOINT);
+
+        self.call.execute::<(),HashMap<String, entities::ProjectInfo>>("list projects", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /projects/?query='
+    ///
+    /// V02.14
+    pub fn query_projects(&mut self, query: &str, limit: Option<usize>) -> GGRResult<Vec<entities::ProjectInfo>> {
+        let config = config::Config::new(self.call.get_base());
+        if let Err(x) = config.check_version("GET /projects/?query=".into(), "2.14.0".into()) {
+            return Err(x);
+        }
+
+        let mut querystring = format!("query={}", query);
+        if let Some(limit) = limit {
+            querystring = format!("{}&n={}", querystring, limit);
+        }
+        self.call.set_url_query(Some(&querystring));
+
+        let path = format!("{}/", ENDPOINT);
+
+        self.call.execute::<(),Vec<entities::ProjectInfo>>("query projects", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /projects/{project-name}'
+    pub fn get_project(&self, project: &str) -> GGRResult<entities::ProjectInfo> {
+        let path = Projects::project_path(project)?;
+
+        self.call.execute::<(),entities::ProjectInfo>("get project", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /projects/{project-name}'
+    pub fn create_project(&self, project: &str, projectinput: &entities::ProjectInput) -> GGRResult<entities::ProjectInfo> {
+        let path = Projects::project_path(project)?;
+
+        self.call.execute::<&entities::ProjectInput,entities::ProjectInfo>("create project", &path, call::CallMethod::Put, Some(&projectinput))
+    }
+
+    /// api function 'GET /projects/{project-name}/parent'
+    pub fn get_project_parent(&self, project: &str) -> GGRResult<String> {
+        let path = format!("{}/parent", Projects::project_path(project)?);
+
+        self.call.execute::<(),String>("get project parent", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /projects/{project-name}/parent'
+    pub fn set_project_parent(&self, project: &str, parent: &str, message: Option<&str>) -> GGRResult<String> {
+        let path = format!("{}/parent", Projects::project_path(project)?);
+
+        let parentinput = entities::ProjectParentInput {
+            parent: parent.into(),
+            commit_message: message.map(|s| s.to_string()),
+        };
+
+        self.call.execute::<&entities::ProjectParentInput,String>("set project parent", &path, call::CallMethod::Put, Some(&&parentinput))
+    }
+
+    /// api function 'GET /projects/{project-name}/HEAD'
+    pub fn get_head(&self, project: &str) -> GGRResult<String> {
+        let path = format!("{}/HEAD", Projects::project_path(project)?);
+
+        self.call.execute::<(),String>("get project head", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /projects/{project-name}/HEAD'
+    pub fn set_head(&self, project: &str, reference: &str) -> GGRResult<String> {
+        let path = format!("{}/HEAD", Projects::project_path(project)?);
+
+        let headinput = entities::HeadInput {
+            reference: reference.into(),
+        };
+
+        self.call.execute::<&entities::HeadInput,String>("set project head", &path, call::CallMethod::Put, Some(&&headinput))
+    }
+
+    /// api function 'GET /projects/{project-name}/branches/'
+    pub fn list_branches(&self, project: &str) -> GGRResult<Vec<entities::BranchInfo>> {
+        let path = format!("{}/branches/", Projects::project_path(project)?);
+
+        self.call.execute::<(),Vec<entities::BranchInfo>>("list branches", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /projects/{project-name}/branches/{branch-id}'
+    pub fn get_branch(&self, project: &str, branch: &str) -> GGRResult<entities::BranchInfo> {
+        let path = format!("{}/branches/{}", Projects::project_path(project)?, call::encode_path_segment(branch));
+
+        self.call.execute::<(),entities::BranchInfo>("get branch", &path, call::CallMethod::Get, None)
+    }
+
+    /// convenience function to check that `branch` exists in `project`
+    pub fn branch_exists(&self, project: &str, branch: &str) -> GGRResult<bool> {
+        match self.get_branch(project, branch) {
+            Ok(_) => Ok(true),
+            Err(GGRError::GerritApiError(GerritError::GerritApi(404, _))) => Ok(false),
+            Err(x) => Err(x),
+        }
+    }
+
+    /// api function 'PUT /projects/{project-name}/branches/{branch-id}'
+    ///
+    /// Without a `revision` the new branch is based on HEAD.
+    pub fn create_branch(&self, project: &str, branch: &str, revision: Option<&str>) -> GGRResult<entities::BranchInfo> {
+        let path = format!("{}/branches/{}", Projects::project_path(project)?, call::encode_path_segment(branch));
+
+        let branchinput = entities::BranchInput {
+            reference: None,
+            revision: revision.map(|s| s.to_string()),
+        };
+
+        self.call.execute::<&entities::BranchInput,entities::BranchInfo>("create branch", &path, call::CallMethod::Put, Some(&&branchinput))
+    }
+
+    /// api function 'DELETE /projects/{project-name}/branches/{branch-id}'
+    pub fn delete_branch(&self, project: &str, branch: &str) -> GGRResult<()> {
+        let path = format!("{}/branches/{}", Projects::project_path(project)?, call::encode_path_segment(branch));
+
+        self.call.execute::<(),()>("delete branch", &path, call::CallMethod::Delete, None)
+    }
+
+    /// api function 'GET /projects/{project-name}/tags/'
+    pub fn list_tags(&self, project: &str) -> GGRResult<Vec<entities::TagInfo>> {
+        let path = format!("{}/tags/", Projects::project_path(project)?);
+
+        self.call.execute::<(),Vec<entities::TagInfo>>("list tags", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'GET /projects/{project-name}/tags/{tag-id}'
+    pub fn get_tag(&self, project: &str, tag: &str) -> GGRResult<entities::TagInfo> {
+        let path = format!("{}/tags/{}", Projects::project_path(project)?, call::encode_path_segment(tag));
+
+        self.call.execute::<(),entities::TagInfo>("get tag", &path, call::CallMethod::Get, None)
+    }
+
+    /// api function 'PUT /projects/{project-name}/tags/{tag-id}'
+    ///
+    /// With a `message` an annotated tag is created.
+    ///
+    /// V02.14
+    pub fn create_tag(&self, project: &str, tag: &str, revision: Option<&str>, message: Option<&str>) -> GGRResult<entities::TagInfo> {
+        let path = format!("{}/tags/{}", Projects::project_path(project)?, call::encode_path_segment(tag));
+
+        let taginput = entities::TagInput {
+            reference: None,
+            revision: revision.map(|s| s.to_string()),
+            message: message.map(|s| s.to_string()),
+        };
+
+        self.call.execute::<&entities::TagInput,entities::TagInfo>("create tag", &path, call::CallMethod::Put, Some(&&taginput))
+    }
+
+    /// api function 'DELETE /projects/{project-name}/tags/{tag-id}'
+    ///
+    /// V02.15
+    pub fn delete_tag(&self, project: &str, tag: &str) -> GGRResult<()> {
+        let path = format!("{}/tags/{}", Projects::project_path(project)?, call::encode_path_segment(tag));
+
+        self.call.execute::<(),()>("delete tag", &path, call::CallMethod::Delete, None)
+    }
+}
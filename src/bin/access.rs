@@ -0,0 +1,308 @@
+//! manage endpoint `/access/`
+
+use clap::{self, App, SubCommand, Arg};
+use git2;
+use libgerrit::error::GGRError;
+use libgerrit::error::GGRResult;
+use libgerrit::gerrit::Gerrit;
+use libgerrit::entities;
+use config;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use url;
+
+/// returns the *Access* part of gerrit-rusts menu
+pub fn menu<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("access")
+    .about("access rights of projects")
+    .subcommand(SubCommand::with_name("show")
+                .about("print the effective push, submit and label permissions of projects")
+                .arg(Arg::with_name("project")
+                     .help("the project name")
+                     .takes_value(true)
+                     .multiple(true)
+                     .required_unless("all-submodules")
+                     .index(1)
+                )
+                .arg(Arg::with_name("all-submodules")
+                     .help("use the project of the current repository and all of its submodules")
+                     .long("all-submodules")
+                     .short("s")
+                )
+                .arg(Arg::with_name("all-permissions")
+                     .help("print all permissions, not only push, submit and labels")
+                     .long("all-permissions")
+                     .short("a")
+                )
+                .after_help("Inherited rules are marked with the name of the parent project. Rules of a parent \
+                            project are hidden if the permission is exclusive on the same ref, except `BLOCK` \
+                            rules.")
+    )
+}
+
+/// proxy function of implemented features
+///
+/// Currently implemented sub commands:
+///
+/// * show
+pub fn manage(x: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    match x.subcommand() {
+        ("show", Some(y)) => { show(y, config) },
+        _ => {
+            println!("{}", x.usage());
+            Ok(())
+        },
+    }
+}
+
+/// print effective permissions of projects
+fn show(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let mut projects = y.values_of_lossy("project").unwrap_or_default();
+    if y.is_present("all-submodules") {
+        projects.append(&mut submodule_projects(config.get_base_url())?);
+    }
+    let all_permissions = y.is_present("all-permissions");
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let mut access = gerrit.access();
+
+    /* fetch the access rights of the projects and all of their parents */
+    let mut rights: HashMap<String, entities::ProjectAccessInfo> = HashMap::new();
+    let mut missing: Vec<String> = projects.clone();
+    while !missing.is_empty() {
+        let fetched = access.list_access_rights(missing.iter().map(|p| p.as_str()).collect())?;
+        missing = fetched.values()
+            .filter_map(|pai| pai.inherits_from.as_ref())
+            .filter_map(|parent| parent.name.clone())
+            .filter(|parent| !rights.contains_key(parent) && !fetched.contains_key(parent))
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+        rights.extend(fetched);
+    }
+
+    for project in &projects {
+        let chain = inheritance_chain(project, &rights);
+        if chain.is_empty() {
+            println!("* {}: no access rights visible", project);
+            continue;
+        }
+
+        println!("* {}{}", project,
+                 if chain.len() > 1 { format!(" (inherits from {})", chain[1..].join(" -> ")) } else { String::new() });
+
+        /* collect the rules per ref and permission, rules of the project first */
+        let mut lines: HashMap<String, Vec<String>> = HashMap::new();
+        let mut exclusive: HashSet<(String, String)> = HashSet::new();
+        for (level, name) in chain.iter().enumerate() {
+            let pai = &rights[name];
+            let mut refs: Vec<&String> = pai.local.keys().collect();
+            refs.sort();
+            for reference in refs {
+                let mut permissions: Vec<(&String, &entities::PermissionInfo)> = pai.local[reference].permissions.iter()
+                    .filter(|&(permission, _)| all_permissions || is_reported_permission(permission))
+                    .collect();
+                permissions.sort_by(|a, b| a.0.cmp(b.0));
+
+                for (permission, pi) in permissions {
+                    let key = (reference.clone(), permission.clone());
+                    let overruled = exclusive.contains(&key);
+
+                    let mut rules: Vec<String> = pi.rules.iter()
+                        .filter(|&(_, rule)| !overruled || rule.action == entities::PermissionRuleInfoAction::BLOCK)
+                        .map(|(group, rule)| {
+                            let groupname = pai.groups.as_ref()
+                                .and_then(|groups| groups.get(group))
+                                .and_then(|g| g.name.clone())
+                                .unwrap_or_else(|| group.clone());
+                            format_rule(permission, &groupname, rule, if level > 0 { Some(name.as_str()) } else { None })
+                        })
+                        .collect();
+                    rules.sort();
+                    lines.entry(reference.clone()).or_default().append(&mut rules);
+
+                    if pi.exclusive.unwrap_or(false) {
+                        exclusive.insert(key);
+                    }
+                }
+            }
+        }
+
+        let mut refs: Vec<&String> = lines.keys().filter(|r| !lines[*r].is_empty()).collect();
+        refs.sort();
+        for reference in refs {
+            println!("  {}", reference);
+            for line in &lines[reference] {
+                println!("    {}", line);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// returns `project` and all of its parents, starting with `project`
+fn inheritance_chain(project: &str, rights: &HashMap<String, entities::ProjectAccessInfo>) -> Vec<String> {
+    let mut chain: Vec<String> = Vec::new();
+    let mut current = Some(project.to_string());
+
+    while let Some(name) = current {
+        if chain.contains(&name) || !rights.contains_key(&name) {
+            break;
+        }
+        current = rights[&name].inherits_from.as_ref().and_then(|parent| parent.name.clone());
+        chain.push(name);
+    }
+
+    chain
+}
+
+/// permissions which are part of the report without `--all-permissions`
+fn is_reported_permission(permission: &str) -> bool {
+    permission == "push" || permission == "submit" || permission.starts_with("label-")
+}
+
+/// format one rule of a permission
+fn format_rule(permission: &str, group: &str, rule: &entities::PermissionRuleInfo, inherited_from: Option<&str>) -> String {
+    let mut line = format!("{:25} {:5} ", permission, format!("{:?}", rule.action));
+
+    if let (Some(min), Some(max)) = (rule.min, rule.max) {
+        line.push_str(&format!("{:+}..{:+} ", min, max));
+    }
+    line.push_str(group);
+    if rule.force.unwrap_or(false) {
+        line.push_str(" (force)");
+    }
+    if let Some(parent) = inherited_from {
+        line.push_str(&format!(" [{}]", parent));
+    }
+
+    line
+}
+
+/// returns the project names of the current repository and all of its submodules
+fn submodule_projects(base_url: &str) -> GGRResult<Vec<String>> {
+    let base_path = url::Url::parse(base_url)?.path().trim_matches('/').to_string();
+
+    let repo = git2::Repository::discover(".")?;
+    let remotes = repo.remotes()?;
+    let remote_name = if remotes.iter().any(|r| r == Some("origin")) {
+        "origin"
+    } else {
+        remotes.get(0).ok_or_else(|| GGRError::General("repository without remote".into()))?
+    };
+    let remote = repo.find_remote(remote_name)?;
+    let remote_url = remote.url().ok_or_else(|| GGRError::General(format!("remote {} without url", remote_name)))?;
+    let superproject = url_to_project(remote_url, &base_path)
+        .ok_or_else(|| GGRError::General(format!("no project name found in url {}", remote_url)))?;
+
+    let mut projects = vec!(superproject.clone());
+    for submodule in repo.submodules()? {
+        let url = match submodule.url() {
+            Some(url) => url,
+            None => {
+                println!("submodule {} without url, ignored", submodule.name().unwrap_or("?"));
+                continue;
+            }
+        };
+
+        let project = if url.starts_with("./") || url.starts_with("../") {
+            relative_project(&superproject, url)
+        } else {
+            url_to_project(url, &base_path)
+        };
+
+        match project {
+            Some(project) => projects.push(project),
+            None => println!("no project name found in url {}, ignored", url),
+        }
+    }
+
+    Ok(projects)
+}
+
+/// extract the gerrit project name of a remote url
+///
+/// `base_path` is the path of the gerrit server url, without leading and trailing slashes.
+fn url_to_project(url: &str, base_path: &str) -> Option<String> {
+    let path = if url.contains("://") {
+        url::Url::parse(url).ok()?.path().to_string()
+    } else {
+        /* scp like syntax: [user@]host:path */
+        let colon_at = url.find(':')?;
+        url[colon_at + 1..].to_string()
+    };
+
+    let mut project = path.trim_matches('/');
+    if !base_path.is_empty() && project.starts_with(&format!("{}/", base_path)) {
+        project = &project[base_path.len() + 1..];
+    }
+    if project.starts_with("a/") {
+        project = &project[2..];
+    }
+    if project.ends_with(".git") {
+        project = &project[..project.len() - 4];
+    }
+
+    if project.is_empty() {
+        None
+    } else {
+        Some(project.to_string())
+    }
+}
+
+/// resolve a relative submodule url against the project name of the superproject
+fn relative_project(superproject: &str, url: &str) -> Option<String> {
+    let mut components: Vec<&str> = superproject.split('/').collect();
+
+    for component in url.trim_end_matches(".git").split('/') {
+        match component {
+            "" | "." => {},
+            ".." => { components.pop()?; },
+            x => components.push(x),
+        }
+    }
+
+    if components.is_empty() {
+        None
+    } else {
+        Some(components.join("/"))
+    }
+}
+
+#[test]
+fn test_url_to_project() {
+    assert_eq!(url_to_project("https://host/gerrit/a/tools/ggr.git", "gerrit"), Some("tools/ggr".into()));
+    assert_eq!(url_to_project("https://host/tools/ggr", ""), Some("tools/ggr".into()));
+    assert_eq!(url_to_project("ssh://user@host:29418/tools/ggr", "gerrit"), Some("tools/ggr".into()));
+    assert_eq!(url_to_project("user@host:tools/ggr.git", ""), Some("tools/ggr".into()));
+    assert_eq!(url_to_project("https://host/", ""), None);
+}
+
+#[test]
+fn test_relative_project() {
+    assert_eq!(relative_project("platform/super", "../lib"), Some("platform/lib".into()));
+    assert_eq!(relative_project("platform/super", "./lib.git"), Some("platform/super/lib".into()));
+    assert_eq!(relative_project("super", "../../lib"), None);
+}
+
+#[test]
+fn test_format_rule() {
+    let rule = entities::PermissionRuleInfo {
+        action: entities::PermissionRuleInfoAction::ALLOW,
+        force: Some(true),
+        min: None,
+        max: None,
+    };
+    assert_eq!(format_rule("push", "Project Owners", &rule, None),
+               format!("{:25} {:5} Project Owners (force)", "push", "ALLOW"));
+
+    let rule = entities::PermissionRuleInfo {
+        action: entities::PermissionRuleInfoAction::ALLOW,
+        force: None,
+        min: Some(-2),
+        max: Some(2),
+    };
+    assert_eq!(format_rule("label-Code-Review", "Registered Users", &rule, Some("All-Projects")),
+               format!("{:25} {:5} -2..+2 Registered Users [All-Projects]", "label-Code-Review", "ALLOW"));
+}
@@ -11,6 +11,9 @@ use gron::ToGron;
 use serde_json;
 use regex;
 use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::process::Command;
 use topic;
 
 /// returns the *Changes* part of gerrit-rusts menu
@@ -66,6 +69,118 @@ pub fn menu<'a, 'b>() -> App<'a, 'b> {
                          .index(1)
                     )
         )
+        .subcommand(SubCommand::with_name("edit-message")
+                    .about("edit the commit message of a change with $EDITOR")
+                    .arg(Arg::with_name("changeid")
+                         .help("the changeid")
+                         .takes_value(true)
+                         .required(true)
+                         .index(1)
+                    )
+                    .arg(Arg::with_name("keep-edit")
+                         .help("don't publish the change edit")
+                         .long("keep-edit")
+                    )
+                    .arg(Arg::with_name("notify")
+                         .help("Notification hint for publishing, without this the gerrit server default is used")
+                         .long("notify")
+                         .short("n")
+                         .takes_value(true)
+                         .possible_values(&["all", "none", "owner", "owner_reviewers"])
+                    )
+        )
+        .subcommand(SubCommand::with_name("included-in")
+                    .about("list branches and tags which contain a merged change")
+                    .arg(Arg::with_name("changeid")
+                         .help("the changeid")
+                         .takes_value(true)
+                         .required(true)
+                         .index(1)
+                    )
+        )
+        .subcommand(SubCommand::with_name("star")
+                    .about("star a change, without labels the default star is set")
+                    .arg(Arg::with_name("changeid")
+                         .help("the changeid")
+                         .takes_value(true)
+                         .required(true)
+                         .index(1)
+                    )
+                    .arg(Arg::with_name("label")
+                         .help("star labels to add, usable in queries with `star:<label>`")
+                         .takes_value(true)
+                         .multiple(true)
+                         .index(2)
+                    )
+        )
+        .subcommand(SubCommand::with_name("unstar")
+                    .about("unstar a change, without labels the default star is removed")
+                    .arg(Arg::with_name("changeid")
+                         .help("the changeid")
+                         .takes_value(true)
+                         .required(true)
+                         .index(1)
+                    )
+                    .arg(Arg::with_name("label")
+                         .help("star labels to remove")
+                         .takes_value(true)
+                         .multiple(true)
+                         .index(2)
+                    )
+        )
+        .subcommand(SubCommand::with_name("ignore")
+                    .about("ignore or mute a change")
+                    .arg(Arg::with_name("changeid")
+                         .help("the changeid")
+                         .takes_value(true)
+                         .required(true)
+                         .index(1)
+                    )
+                    .arg(Arg::with_name("mute")
+                         .help("mute the change instead, it is marked as read until a new patch set is uploaded")
+                         .long("mute")
+                         .short("m")
+                    )
+                    .arg(Arg::with_name("unset")
+                         .help("unignore or unmute the change")
+                         .long("unset")
+                         .short("u")
+                    )
+        )
+        .subcommand(SubCommand::with_name("related")
+                    .about("print the relation chain of the current revision of a change")
+                    .arg(Arg::with_name("changeid")
+                         .help("the changeid")
+                         .takes_value(true)
+                         .required(true)
+                         .index(1)
+                    )
+                    .after_help("* `@` marks the requested change, `o` a current and `x` an outdated patch set")
+        )
+        .subcommand(SubCommand::with_name("files")
+                    .about("list the files of the current revision of a change")
+                    .arg(Arg::with_name("changeid")
+                         .help("the changeid")
+                         .takes_value(true)
+                         .required(true)
+                         .index(1)
+                    )
+        )
+        .subcommand(SubCommand::with_name("diff")
+                    .about("print the diff of the current revision of a change")
+                    .arg(Arg::with_name("changeid")
+                         .help("the changeid")
+                         .takes_value(true)
+                         .required(true)
+                         .index(1)
+                    )
+                    .arg(Arg::with_name("file")
+                         .help("restrict the diff to this files")
+                         .takes_value(true)
+                         .multiple(true)
+                         .index(2)
+                    )
+        )
 }
 
 /// proxy function of implemented features
@@ -73,10 +188,27 @@ pub fn menu<'a, 'b>() -> App<'a, 'b> {
 /// Currently implemented sub commands:
 ///
 /// * query
+/// * fetch
+/// * edit-message
+/// * included-in
+/// * star
+/// * unstar
+/// * ignore
+/// * related
+/// * files
+/// * diff
 pub fn manage(x: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     match x.subcommand() {
         ("query", Some(y)) => { query(y, config) },
         ("fetch", Some(y)) => { fetch(y, config) },
+        ("edit-message", Some(y)) => { edit_message(y, config) },
+        ("included-in", Some(y)) => { included_in(y, config) },
+        ("star", Some(y)) => { star(y, config, true) },
+        ("unstar", Some(y)) => { star(y, config, false) },
+        ("ignore", Some(y)) => { ignore(y, config) },
+        ("related", Some(y)) => { related(y, config) },
+        ("files", Some(y)) => { files(y, config) },
+        ("diff", Some(y)) => { diff(y, config) },
         _ => {
             println!("{}", x.usage());
             Ok(())
@@ -260,11 +392,12 @@ fn fetch(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     let changeid = y.value_of_lossy("changeid").expect("no changeid provided, see help");
 
     let mut gerrit = Gerrit::new(config.get_base_url());
+    topic::download_commands_hint(&mut gerrit);
     let mut changes = gerrit.changes();
 
     match changes.get_change(&*changeid, Some(vec!("CURRENT_REVISION", "DOWNLOAD_COMMANDS", "CURRENT_COMMIT"))) {
         Ok(change) => {
-            topic::fetch_changeinfos(&[change], true, &changeid, None)
+            topic::fetch_changeinfos(&[change], true, &changeid, None, &config.fetch_transport())
         },
         Err(x) => {
             println!("Error on retrival of {}: {}", changeid, x);
@@ -272,3 +405,333 @@ fn fetch(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
         }
     }
 }
+
+/// edit the commit message of a change on the server
+///
+/// The message of an existing change edit or of the current revision is edited with `$EDITOR`
+/// (defaults to `vi`). Lines starting with '#' are removed.
+fn edit_message(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let changeid = y.value_of_lossy("changeid").expect("no changeid provided, see help");
+    let notify = y.value_of("notify");
+    let dryrun = *config.dry_run();
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let mut changes = gerrit.changes();
+
+    let ci = changes.get_change(&changeid, Some(vec!("CURRENT_REVISION", "CURRENT_COMMIT")))?;
+    let message = match changes.get_edit(&ci.id)? {
+        Some(_) => changes.get_edit_message(&ci.id)?,
+        None => {
+            ci.current_revision.as_ref()
+                .and_then(|rev| ci.revisions.as_ref().and_then(|revisions| revisions.get(rev)))
+                .and_then(|revinfo| revinfo.commit.as_ref())
+                .and_then(|commit| commit.message.clone())
+                .ok_or_else(|| GGRError::General(format!("no commit message for change {} found", ci._number)))?
+        },
+    };
+
+    let path = env::temp_dir().join(format!("ggr-{}-COMMIT_EDITMSG", ci._number));
+    fs::write(&path, format!("{}\n# Edit the commit message of change {} ({}).\n\
+                              # Lines starting with '#' are ignored, the Change-Id has to be unchanged.\n",
+                             message.trim_end(), ci._number, ci.subject))?;
+
+    let editor = env::var("EDITOR").unwrap_or_else(|_| "vi".into());
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$@\"", editor))
+        .arg(&editor)
+        .arg(&path)
+        .status()?;
+    let edited = fs::read_to_string(&path);
+    let _ = fs::remove_file(&path);
+
+    if !status.success() {
+        return Err(GGRError::General(format!("editor '{}' failed, message not changed", editor)));
+    }
+
+    let newmessage = strip_comments(&edited?);
+    if newmessage.trim().is_empty() {
+        return Err(GGRError::General("empty commit message, message not changed".into()));
+    }
+    if newmessage.trim() == message.trim() {
+        println!("commit message of change {} unchanged", ci._number);
+        return Ok(());
+    }
+
+    if dryrun {
+        println!("(dry-run) new commit message of change {}:\n{}", ci._number, newmessage);
+        return Ok(());
+    }
+
+    changes.set_edit_message(&ci.id, &newmessage)?;
+
+    if y.is_present("keep-edit") {
+        println!("commit message of change {} changed in change edit", ci._number);
+        return Ok(());
+    }
+
+    changes.publish_edit(&ci.id, notify)?;
+    println!("commit message of change {} changed and published", ci._number);
+
+    Ok(())
+}
+
+/// removes all lines starting with '#' and surrounding empty lines
+fn strip_comments(message: &str) -> String {
+    let lines: Vec<&str> = message.lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect();
+
+    format!("{}\n", lines.join("\n").trim())
+}
+
+/// print branches and tags which contain a merged change
+fn included_in(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let changeid = y.value_of_lossy("changeid").expect("no changeid provided, see help");
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let mut changes = gerrit.changes();
+
+    let ci = changes.get_change(&changeid, None)?;
+    if ci.status != entities::ChangeInfoChangeStatus::MERGED {
+        println!("* {:5.5} [{:20.20}] {}: not merged ({:?})", ci.change_id, ci.subject, ci.project, ci.status);
+        return Ok(());
+    }
+
+    let mut iii = changes.get_included_in(&ci.id)?;
+    iii.branches.sort();
+    iii.tags.sort();
+
+    println!("* {:5.5} [{:20.20}] {}:", ci.change_id, ci.subject, ci.project);
+    println!("  branches: {}", iii.branches.join(", "));
+    println!("  tags:     {}", iii.tags.join(", "));
+    if let Some(external) = iii.external {
+        let mut names: Vec<&String> = external.keys().collect();
+        names.sort();
+        for name in names {
+            println!("  {}: {}", name, external[name].join(", "));
+        }
+    }
+
+    Ok(())
+}
+
+/// star or unstar a change for the calling account
+fn star(y: &clap::ArgMatches, config: &config::Config, set: bool) -> GGRResult<()> {
+    let changeid = y.value_of_lossy("changeid").expect("no changeid provided, see help");
+    let labels = y.values_of_lossy("label").unwrap_or_default();
+    let dryrun = *config.dry_run();
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let ci = gerrit.changes().get_change(&changeid, None)?;
+
+    if dryrun {
+        println!("* {:5.5} [{:20.20}] {}: would {} ({})", ci.change_id, ci.subject, ci.project,
+                 if set { "star" } else { "unstar" },
+                 if labels.is_empty() { "default star".to_string() } else { labels.join(", ") });
+        return Ok(());
+    }
+
+    let accounts = gerrit.accounts();
+    if labels.is_empty() {
+        if set {
+            accounts.star_change("self", &ci.id)?;
+        } else {
+            accounts.unstar_change("self", &ci.id)?;
+        }
+        println!("* {:5.5} [{:20.20}] {}: {}", ci.change_id, ci.subject, ci.project, if set { "starred" } else { "unstarred" });
+    } else {
+        let labels: Vec<&str> = labels.iter().map(|l| l.as_str()).collect();
+        let (add, remove) = if set { (labels, Vec::new()) } else { (Vec::new(), labels) };
+        let current = accounts.update_star_labels("self", &ci.id, add, remove)?;
+        println!("* {:5.5} [{:20.20}] {}: stars: {}", ci.change_id, ci.subject, ci.project, current.join(", "));
+    }
+
+    Ok(())
+}
+
+/// ignore, mute or revert this for a change
+fn ignore(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let changeid = y.value_of_lossy("changeid").expect("no changeid provided, see help");
+    let mute = y.is_present("mute");
+    let set = !y.is_present("unset");
+    let dryrun = *config.dry_run();
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let mut changes = gerrit.changes();
+    let ci = changes.get_change(&changeid, None)?;
+
+    let action = match (mute, set) {
+        (false, true) => "ignore",
+        (false, false) => "unignore",
+        (true, true) => "mute",
+        (true, false) => "unmute",
+    };
+
+    if dryrun {
+        println!("* {:5.5} [{:20.20}] {}: would {}", ci.change_id, ci.subject, ci.project, action);
+        return Ok(());
+    }
+
+    if mute {
+        changes.mute_change(&ci.id, set)?;
+    } else {
+        changes.ignore_change(&ci.id, set)?;
+    }
+    println!("* {:5.5} [{:20.20}] {}: {}", ci.change_id, ci.subject, ci.project, action);
+
+    Ok(())
+}
+
+/// print the relation chain of a change
+fn related(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let changeid = y.value_of_lossy("changeid").expect("no changeid provided, see help");
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let mut changes = gerrit.changes();
+
+    let ci = changes.get_change(&changeid, None)?;
+    let related = changes.get_related_changes(&changeid, "current")?;
+
+    if related.changes.is_empty() {
+        println!("no related changes for {}", ci._number);
+        return Ok(());
+    }
+
+    print!("{}", related_to_graph(&related, ci._number));
+
+    Ok(())
+}
+
+/// renders a relation chain as ASCII graph, newest change on top
+///
+/// `number` is the change number of the requested change.
+fn related_to_graph(related: &entities::RelatedChangesInfo, number: u64) -> String {
+    let mut out = String::new();
+
+    for (pos, rc) in related.changes.iter().enumerate() {
+        let revision = rc._revision_number.unwrap_or(0);
+        let current = rc._current_revision_number.unwrap_or(revision);
+
+        let marker = if rc._change_number == Some(number) {
+            '@'
+        } else if revision < current {
+            'x'
+        } else {
+            'o'
+        };
+
+        let changenumber = rc._change_number.map(|n| n.to_string()).unwrap_or_else(|| "-".into());
+        let subject = rc.commit.subject.clone().unwrap_or_default();
+        let commit = rc.commit.commit.clone().unwrap_or_default();
+
+        let mut line = format!("{} {:>6}/{:<3} {:8.8} {}", marker, changenumber, revision, commit, subject);
+        if let Some(ref status) = rc.status {
+            if status != "NEW" {
+                line.push_str(&format!(" ({})", status));
+            }
+        }
+        if revision < current {
+            line.push_str(&format!(" [outdated, current is {}]", current));
+        }
+
+        out.push_str(line.trim_end());
+        out.push('\n');
+        if pos + 1 < related.changes.len() {
+            out.push_str("|\n");
+        }
+    }
+
+    out
+}
+
+/// list files of the current revision with inserted and deleted lines
+fn files(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let changeid = y.value_of_lossy("changeid").expect("no changeid provided, see help");
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let mut changes = gerrit.changes();
+
+    let files = changes.list_files(&changeid, "current", None, None, false)?;
+
+    let mut names: Vec<&String> = files.keys().collect();
+    names.sort();
+
+    for name in names {
+        let fi = &files[name];
+        let status = fi.status.clone().unwrap_or_else(|| "M".into());
+        if fi.binary.unwrap_or(false) {
+            println!("{} {:>6} {:>6} {}", status, "-", "-", name);
+        } else {
+            println!("{} {:>+6} {:>6} {}", status, fi.lines_inserted.unwrap_or(0), -(fi.lines_deleted.unwrap_or(0) as i64), name);
+        }
+    }
+
+    Ok(())
+}
+
+/// print a unified like diff of the current revision
+fn diff(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let changeid = y.value_of_lossy("changeid").expect("no changeid provided, see help");
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let mut changes = gerrit.changes();
+
+    let files = match y.values_of_lossy("file") {
+        Some(files) => files,
+        None => {
+            let mut files: Vec<String> = changes.list_files(&changeid, "current", None, None, false)?
+                .keys()
+                .filter(|f| *f != "/COMMIT_MSG" && *f != "/MERGE_LIST")
+                .cloned()
+                .collect();
+            files.sort();
+            files
+        }
+    };
+
+    for file in files {
+        let diffinfo = changes.get_diff(&changeid, "current", &file, None, false)?;
+        print!("{}", diff_to_string(&diffinfo));
+    }
+
+    Ok(())
+}
+
+/// renders a `DiffInfo` entity in unified diff like format
+fn diff_to_string(diffinfo: &entities::DiffInfo) -> String {
+    let mut out = String::new();
+
+    for line in &diffinfo.diff_header {
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    if diffinfo.binary.unwrap_or(false) {
+        out.push_str("Binary files differ\n");
+        return out;
+    }
+
+    for content in &diffinfo.content {
+        if let Some(skip) = content.skip {
+            out.push_str(&format!("@@ {} common lines skipped @@\n", skip));
+        }
+        for line in content.ab.iter().flat_map(|l| l.iter()) {
+            out.push_str(&format!(" {}\n", line));
+        }
+        for line in content.a.iter().flat_map(|l| l.iter()) {
+            out.push_str(&format!("-{}\n", line));
+        }
+        for line in content.b.iter().flat_map(|l| l.iter()) {
+            out.push_str(&format!("+{}\n", line));
+        }
+    }
+
+    out
+}
+
+#[test]
+fn test_strip_comments() {
+    assert_eq!(strip_comments("subject\n\nbody\n# comment\n\nChange-Id: I1\n# another\n\n"), "subject\n\nbody\n\nChange-Id: I1\n");
+    assert_eq!(strip_comments("\n# only comment\nsubject"), "subject\n");
+}
@@ -9,6 +9,7 @@ use std::fmt;
 use std::fs;
 use std::io;
 use std::path::Path;
+use std::path::PathBuf;
 use toml_config;
 
 pub fn menu<'a, 'b>() -> App<'a, 'b> {
@@ -60,6 +61,27 @@ pub struct Config {
     root: bool,
     /// do not execute anything if dryrun is true
     dryrun: bool,
+    /// fetch scheme (`http` or `ssh`) whose url is used for fetching, defaults to `http`
+    fetch_scheme: Option<String>,
+    /// private key file for ssh remotes, without it the ssh-agent is used
+    ssh_key: Option<String>,
+    /// passphrase of an encrypted `ssh_key`
+    ssh_key_passphrase: Option<String>,
+    /// user name for ssh remotes without user name in the url
+    ssh_username: Option<String>,
+}
+
+/// Settings for fetching of patch sets via the remotes of the local repositories
+#[derive(Clone, Debug)]
+pub struct FetchTransport {
+    /// scheme of the fetch information whose url is used for fetching
+    pub scheme: String,
+    /// private key file for ssh remotes, `None` uses the ssh-agent
+    pub ssh_key: Option<PathBuf>,
+    /// passphrase of an encrypted `ssh_key`
+    pub ssh_key_passphrase: Option<String>,
+    /// user name for ssh remotes without user name in the url
+    pub ssh_username: Option<String>,
 }
 
 impl Default for Config {
@@ -70,6 +92,10 @@ impl Default for Config {
             password: None,
             root: true,
             dryrun: false,
+            fetch_scheme: None,
+            ssh_key: None,
+            ssh_key_passphrase: None,
+            ssh_username: None,
         }
     }
 }
@@ -80,6 +106,12 @@ impl fmt::Display for Config {
                api = self.api.clone(),
         ));
         try!(writeln!(f, "  user/pass ... : from .netrc file"));
+        try!(writeln!(f, "  fetch scheme  : {scheme}",
+               scheme = self.fetch_scheme.clone().unwrap_or_else(|| "http".into()),
+        ));
+        try!(writeln!(f, "  ssh key ..... : {key}",
+               key = self.ssh_key.clone().unwrap_or_else(|| "from ssh-agent".into()),
+        ));
         write!(f, "  root ........ : {root}", root = self.root)
     }
 }
@@ -116,6 +148,21 @@ impl Config {
     pub fn set_dry_run(&mut self, new: bool) {
         self.dryrun = new
     }
+
+    /// returns the fetch settings, a leading `~/` of the ssh key file is expanded
+    pub fn fetch_transport(&self) -> FetchTransport {
+        FetchTransport {
+            scheme: self.fetch_scheme.clone().unwrap_or_else(|| "http".into()),
+            ssh_key: self.ssh_key.as_ref().map(|key| {
+                match (key.starts_with("~/"), env::home_dir()) {
+                    (true, Some(home)) => home.join(&key[2..]),
+                    _ => PathBuf::from(key),
+                }
+            }),
+            ssh_key_passphrase: self.ssh_key_passphrase.clone(),
+            ssh_username: self.ssh_username.clone(),
+        }
+    }
 }
 
 /// Represents a config file filesystem object
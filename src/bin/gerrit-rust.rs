@@ -11,13 +11,16 @@ extern crate gron;
 extern crate log;
 extern crate regex;
 extern crate rustc_serialize;
+#[cfg_attr(test, macro_use)]
 extern crate serde_json;
 extern crate toml_config;
 extern crate url;
 extern crate netrc;
 
+pub mod access;
 pub mod changes;
 pub mod config;
+pub mod server;
 pub mod topic;
 pub mod gerritapi;
 
@@ -65,7 +68,9 @@ fn main() {
          )
         .subcommand(topic::menu())
         .subcommand(changes::menu())
+        .subcommand(access::menu())
         .subcommand(config::menu())
+        .subcommand(server::menu())
         .subcommand(gerritapi::menu())
         ;
 
@@ -89,7 +94,9 @@ fn main() {
     let out = match matches.subcommand() {
         ("topic", Some(x)) => { topic::manage(x, &config) },
         ("changes", Some(x)) => { changes::manage(x, &config) },
+        ("access", Some(x)) => { access::manage(x, &config) },
         ("config", Some(x)) => { config::manage(x) },
+        ("server", Some(x)) => { server::manage(x, &config) },
         ("gerritapi", Some(x)) => { gerritapi::manage(x, &config) },
         _ => { let _ = app.print_help(); Ok(()) },
     };
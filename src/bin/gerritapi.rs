@@ -77,6 +77,32 @@ pub fn menu<'a, 'b>() -> App<'a, 'b> {
                             )
                 )
     )
+    .subcommand(SubCommand::with_name("groups")
+                .about("Groups endpoint")
+                .subcommand(SubCommand::with_name("list")
+                            .about("List groups")
+                            .arg(Arg::with_name("user")
+                                 .takes_value(true)
+                                 .long("user")
+                                 .short("u")
+                                 .help("only groups with this user as member")
+                            )
+                )
+                .subcommand(SubCommand::with_name("members")
+                            .about("List members of a group")
+                            .arg(Arg::with_name("groupid")
+                                 .required(true)
+                                 .takes_value(true)
+                                 .help("group name or id")
+                                 .index(1)
+                            )
+                            .arg(Arg::with_name("recursive")
+                                 .long("recursive")
+                                 .short("r")
+                                 .help("resolve members of included groups")
+                            )
+                )
+    )
     .subcommand(SubCommand::with_name("config")
                 .about("Config endpoint")
                 .arg(Arg::with_name("version")
@@ -89,6 +115,7 @@ pub fn menu<'a, 'b>() -> App<'a, 'b> {
 pub fn manage(x: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     match x.subcommand() {
         ("changes", Some(y)) => { changes(y, config) },
+        ("groups", Some(y)) => { groups(y, config) },
         ("config", Some(y)) => { configs(y, config) },
         _ => {
             println!("{}", x.usage());
@@ -110,6 +137,49 @@ fn configs(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     Ok(())
 }
 
+fn groups(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let mut gerrit = Gerrit::new(config.get_base_url());
+
+    match y.subcommand() {
+        ("list", Some(opt)) => {
+            let user = opt.value_of("user");
+
+            match gerrit.groups().list_groups(user, None, None, None) {
+                Ok(groups) => {
+                    for (name, group) in groups {
+                        println!("* {:30} {}", name, group.description.unwrap_or_default());
+                    }
+                },
+                Err(x) => println!("Error: {:?}", x),
+            }
+        },
+
+        ("members", Some(opt)) => {
+            let groupid = opt.value_of("groupid").unwrap();
+            let recursive = opt.is_present("recursive");
+
+            match gerrit.groups().list_members(groupid, recursive) {
+                Ok(members) => {
+                    for member in members {
+                        println!("* {:30} {:30} {}",
+                                 member.name.unwrap_or_else(|| "unknown name".into()),
+                                 member.email.unwrap_or_else(|| "unknown mail".into()),
+                                 member._account_id.unwrap_or(99999999));
+                    }
+                },
+                Err(x) => println!("Error: {:?}", x),
+            }
+        },
+
+        e => {
+            println!("unknown subcommand {}", e.0);
+            println!("{}", y.usage());
+        }
+    }
+
+    Ok(())
+}
+
 fn changes(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     let mut gerrit = Gerrit::new(config.get_base_url());
 
@@ -0,0 +1,244 @@
+//! manage endpoint `/config/server/`
+
+use clap::{self, App, SubCommand, Arg};
+use libgerrit::error::GGRError;
+use libgerrit::error::GGRResult;
+use libgerrit::gerrit::Gerrit;
+use libgerrit::entities;
+use config;
+
+/// returns the *Server* part of gerrit-rusts menu
+pub fn menu<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("server")
+    .about("server administration")
+    .subcommand(SubCommand::with_name("info")
+                .about("print the configuration of the gerrit server")
+                .arg(Arg::with_name("summary")
+                     .help("print also a summary of tasks, memory and threads")
+                     .long("summary")
+                     .short("s")
+                )
+                .arg(Arg::with_name("jvm")
+                     .help("include a summary of the JVM, only with --summary")
+                     .long("jvm")
+                     .requires("summary")
+                )
+    )
+    .subcommand(SubCommand::with_name("caches")
+                .about("list or flush caches")
+                .arg(Arg::with_name("flush")
+                     .help("flush the given caches, without cache names all caches are flushed")
+                     .long("flush")
+                     .short("f")
+                )
+                .arg(Arg::with_name("cache")
+                     .help("restrict to this caches")
+                     .takes_value(true)
+                     .multiple(true)
+                     .index(1)
+                )
+    )
+    .subcommand(SubCommand::with_name("tasks")
+                .about("list or kill tasks of the background work queues")
+                .arg(Arg::with_name("kill")
+                     .help("kill this task")
+                     .long("kill")
+                     .short("k")
+                     .takes_value(true)
+                     .multiple(true)
+                     .number_of_values(1)
+                     .value_name("TASKID")
+                )
+    )
+}
+
+/// proxy function of implemented features
+///
+/// Currently implemented sub commands:
+///
+/// * info
+/// * caches
+/// * tasks
+pub fn manage(x: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    match x.subcommand() {
+        ("info", Some(y)) => { info(y, config) },
+        ("caches", Some(y)) => { caches(y, config) },
+        ("tasks", Some(y)) => { tasks(y, config) },
+        _ => {
+            println!("{}", x.usage());
+            Ok(())
+        },
+    }
+}
+
+/// print server information and a optional summary
+fn info(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let mut serverconfig = gerrit.config();
+
+    let version = serverconfig.get_version()?;
+    let si = serverconfig.get_server_info()?;
+
+    println!("version:       {}", version);
+    println!("auth:          {}", si.auth.auth_type);
+    println!("sshd:          {}", if si.sshd.is_some() { "enabled" } else { "disabled" });
+    println!("all projects:  {}", si.gerrit.all_projects);
+    println!("all users:     {}", si.gerrit.all_users);
+    println!("large change:  {} files", si.change.large_change);
+    println!("topic submit:  {}", si.change.submit_whole_topic.unwrap_or(false));
+    if let Some(note_db) = si.note_db_enabled {
+        println!("notedb:        {}", note_db);
+    }
+
+    let mut schemes: Vec<&String> = si.download.schemes.keys().collect();
+    schemes.sort();
+    println!("download:");
+    for scheme in schemes {
+        println!("  {:10} {}", scheme, si.download.schemes[scheme].url);
+    }
+    if !si.download.archives.is_empty() {
+        println!("archives:      {}", si.download.archives.join(", "));
+    }
+
+    if y.is_present("summary") {
+        let summary = serverconfig.get_summary(y.is_present("jvm"), false)?;
+        let ts = &summary.task_summary;
+        println!("tasks:         {} total, {} running, {} ready, {} sleeping",
+                 ts.total.unwrap_or(0), ts.running.unwrap_or(0), ts.ready.unwrap_or(0), ts.sleeping.unwrap_or(0));
+        let ms = &summary.mem_summary;
+        println!("memory:        {} total, {} used, {} free, {} max", ms.total, ms.used, ms.free, ms.max);
+        println!("threads:       {} on {} cpus", summary.thread_summary.threads, summary.thread_summary.cpus);
+        if let Some(jvm) = summary.jvm_summary {
+            println!("jvm:           {} {} ({})", jvm.vm_name, jvm.vm_version, jvm.vm_vendor);
+            println!("os:            {} {} {}", jvm.os_name, jvm.os_version, jvm.os_arch);
+            println!("site:          {}", jvm.site);
+        }
+    }
+
+    Ok(())
+}
+
+/// list or flush caches
+fn caches(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let names = y.values_of_lossy("cache").unwrap_or_default();
+    let dryrun = *config.dry_run();
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let serverconfig = gerrit.config();
+
+    if y.is_present("flush") {
+        if dryrun {
+            if names.is_empty() {
+                println!("would flush all caches");
+            } else {
+                println!("would flush caches: {}", names.join(", "));
+            }
+            return Ok(());
+        }
+
+        if names.is_empty() {
+            serverconfig.flush_caches(None)?;
+            println!("all caches flushed");
+        } else {
+            serverconfig.flush_caches(Some(names.iter().map(|n| n.as_str()).collect()))?;
+            println!("caches flushed: {}", names.join(", "));
+        }
+        return Ok(());
+    }
+
+    let caches = serverconfig.list_caches()?;
+    let mut cachenames: Vec<&String> = caches.keys()
+        .filter(|name| names.is_empty() || names.contains(name))
+        .collect();
+    cachenames.sort();
+
+    println!("{:35} {:4} {:>8} {:>8} {:>10} {:>8}", "name", "type", "mem", "disk", "space", "hit");
+    for name in cachenames {
+        println!("{}", cache_line(name, &caches[name]));
+    }
+
+    Ok(())
+}
+
+/// format one line of the cache table
+fn cache_line(name: &str, cache: &entities::CacheInfo) -> String {
+    let hit = match cache.hit_ratio.disk {
+        Some(disk) => format!("{}%/{}%", cache.hit_ratio.mem, disk),
+        None => format!("{}%", cache.hit_ratio.mem),
+    };
+
+    format!("{:35} {:4} {:>8} {:>8} {:>10} {:>8}",
+            name,
+            cache.cache_type,
+            cache.entries.mem.map(|m| m.to_string()).unwrap_or_default(),
+            cache.entries.disk.map(|d| d.to_string()).unwrap_or_default(),
+            cache.entries.space.clone().unwrap_or_default(),
+            hit)
+}
+
+/// list or kill tasks
+fn tasks(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let dryrun = *config.dry_run();
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let serverconfig = gerrit.config();
+
+    if let Some(taskids) = y.values_of_lossy("kill") {
+        let mut failed = Vec::new();
+        for taskid in &taskids {
+            if dryrun {
+                println!("* {}: would kill", taskid);
+                continue;
+            }
+
+            match serverconfig.delete_task(taskid) {
+                Ok(()) => println!("* {}: killed", taskid),
+                Err(x) => {
+                    println!("* {}: {}", taskid, x);
+                    failed.push(taskid.clone());
+                },
+            }
+        }
+
+        if !failed.is_empty() {
+            return Err(GGRError::General(format!("problem with killing of tasks {}", failed.join(", "))));
+        }
+        return Ok(());
+    }
+
+    let tasks = serverconfig.list_tasks()?;
+    for task in tasks {
+        println!("* {:8} {:9} {:20} {:>6} {}{}",
+                 task.id,
+                 format!("{:?}", task.state),
+                 task.start_time,
+                 task.delay,
+                 task.command,
+                 task.project.map(|p| format!(" ({})", p)).unwrap_or_default());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_cache_line() {
+    let mem = entities::CacheInfo {
+        name: None,
+        cache_type: "MEM".into(),
+        entries: entities::EntriesInfo { mem: Some(4), disk: None, space: None },
+        average_get: None,
+        hit_ratio: entities::HitRatioInfo { mem: 94, disk: None },
+    };
+    assert_eq!(cache_line("accounts", &mem),
+               format!("{:35} {:4} {:>8} {:>8} {:>10} {:>8}", "accounts", "MEM", "4", "", "", "94%"));
+
+    let disk = entities::CacheInfo {
+        name: None,
+        cache_type: "DISK".into(),
+        entries: entities::EntriesInfo { mem: Some(10), disk: Some(3733), space: Some("2.00m".into()) },
+        average_get: Some("2.5ms".into()),
+        hit_ratio: entities::HitRatioInfo { mem: 99, disk: Some(8) },
+    };
+    assert_eq!(cache_line("diff", &disk),
+               format!("{:35} {:4} {:>8} {:>8} {:>10} {:>8}", "diff", "DISK", "10", "3733", "2.00m", "99%/8%"));
+}
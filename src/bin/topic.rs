@@ -1,6 +1,7 @@
 
-use clap::{self, SubCommand, App, Arg};
+use clap::{self, SubCommand, App, AppSettings, Arg};
 use git2;
+use std::cmp;
 use std::collections::HashMap;
 use std::env;
 use std::fs::File;
@@ -12,6 +13,7 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
 use std::thread;
+use libgerrit::changes::Changes;
 use libgerrit::error::GGRError;
 use libgerrit::error::GGRResult;
 use libgerrit::error::GerritError;
@@ -108,6 +110,35 @@ pub fn menu<'a, 'b>() -> App<'a, 'b> {
                      .long("verbose")
                      .short("v")
                 )
+                .arg(Arg::with_name("delete-vote")
+                     .help("remove the vote of a reviewer for a label, like 'admin@example.com:Code-Review'")
+                     .long("delete-vote")
+                     .short("d")
+                     .takes_value(true)
+                     .multiple(true)
+                     .number_of_values(1)
+                )
+                .arg(Arg::with_name("notify")
+                     .help("Notification hint for adding reviewers and `--delete-vote`, without this the gerrit server default is used")
+                     .long("notify")
+                     .short("n")
+                     .takes_value(true)
+                     .possible_values(&["all", "none", "owner", "owner_reviewers"])
+                )
+                .arg(Arg::with_name("notify-to")
+                     .help("notify this accounts additionally about added reviewers, useful with `--notify none`")
+                     .long("notify-to")
+                     .takes_value(true)
+                     .multiple(true)
+                )
+                .arg(Arg::with_name("cc")
+                     .help("add the reviewers of `-r` as CC instead of REVIEWER")
+                     .long("cc")
+                )
+                .arg(Arg::with_name("confirm")
+                     .help("confirm adding of groups with many members")
+                     .long("confirm")
+                )
     )
     .subcommand(SubCommand::with_name("abandon")
                 .about("Abandon a topic")
@@ -124,6 +155,56 @@ pub fn menu<'a, 'b>() -> App<'a, 'b> {
                      .takes_value(true)
                 )
     )
+    .subcommand(SubCommand::with_name("wip")
+                .about("Mark all changes of a topic as work in progress")
+                .arg(Arg::with_name("topicname")
+                     .help("topic name")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .arg(Arg::with_name("message")
+                     .help("message added to every change")
+                     .short("m")
+                     .long("message")
+                     .takes_value(true)
+                )
+    )
+    .subcommand(SubCommand::with_name("ready")
+                .about("Mark all changes of a topic as ready for review")
+                .arg(Arg::with_name("topicname")
+                     .help("topic name")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .arg(Arg::with_name("message")
+                     .help("message added to every change")
+                     .short("m")
+                     .long("message")
+                     .takes_value(true)
+                )
+    )
+    .subcommand(SubCommand::with_name("private")
+                .about("Mark all changes of a topic as private")
+                .arg(Arg::with_name("topicname")
+                     .help("topic name")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .arg(Arg::with_name("message")
+                     .help("message added to every change")
+                     .short("m")
+                     .long("message")
+                     .takes_value(true)
+                )
+                .arg(Arg::with_name("unset")
+                     .help("remove the private flag")
+                     .short("u")
+                     .long("unset")
+                )
+    )
     .subcommand(SubCommand::with_name("restore")
                 .about("Restore a topic")
                 .arg(Arg::with_name("topicname")
@@ -139,6 +220,145 @@ pub fn menu<'a, 'b>() -> App<'a, 'b> {
                      .takes_value(true)
                 )
     )
+    .subcommand(SubCommand::with_name("cherry-pick")
+                .about("Cherry pick all changes of a topic to another branch")
+                .arg(Arg::with_name("topicname")
+                     .help("topic name to cherry pick")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .arg(Arg::with_name("branch")
+                     .help("destination branch")
+                     .required(true)
+                     .takes_value(true)
+                     .short("b")
+                     .long("branch")
+                )
+                .arg(Arg::with_name("newtopicname")
+                     .help("topic name for the cherry picked changes")
+                     .takes_value(true)
+                     .short("t")
+                     .long("topic")
+                )
+    )
+    .subcommand(SubCommand::with_name("revert")
+                .about("Revert all merged changes of a topic")
+                .arg(Arg::with_name("topicname")
+                     .help("topic name to revert")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .arg(Arg::with_name("message")
+                     .help("message for revert operation")
+                     .short("m")
+                     .long("message")
+                     .takes_value(true)
+                )
+                .arg(Arg::with_name("newtopicname")
+                     .help("topic name for the revert changes")
+                     .takes_value(true)
+                     .short("t")
+                     .long("topic")
+                )
+    )
+    .subcommand(SubCommand::with_name("move")
+                .about("Move all open changes of a topic to another branch")
+                .arg(Arg::with_name("topicname")
+                     .help("topic name to move")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .arg(Arg::with_name("branch")
+                     .help("destination branch")
+                     .required(true)
+                     .takes_value(true)
+                     .short("b")
+                     .long("branch")
+                )
+                .arg(Arg::with_name("message")
+                     .help("message for move operation")
+                     .short("m")
+                     .long("message")
+                     .takes_value(true)
+                )
+    )
+    .subcommand(SubCommand::with_name("rebase")
+                .about("Rebase all open changes of a topic on server side")
+                .arg(Arg::with_name("topicname")
+                     .help("topic name to rebase")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .after_help("* every change chain of a project is rebased in dependency order, the first change on the tip of his branch")
+    )
+    .subcommand(SubCommand::with_name("hashtag")
+                .about("list, add or remove hashtags of all changes of a topic")
+                .setting(AppSettings::AllowLeadingHyphen)
+                .arg(Arg::with_name("hashtags")
+                     .help("hashtags to add (+foo or foo) or to remove (-foo), followed by the topic name")
+                     .required_unless("query")
+                     .takes_value(true)
+                     .multiple(true)
+                     .allow_hyphen_values(true)
+                     .value_name("HASHTAG|TOPIC")
+                     .index(1)
+                )
+                .arg(Arg::with_name("query")
+                     .help("list the open changes with this hashtag grouped by topic")
+                     .long("query")
+                     .short("q")
+                     .takes_value(true)
+                     .conflicts_with("hashtags")
+                )
+                .after_help("* usage: ggr topic hashtag [+HASHTAG|-HASHTAG ...] TOPIC\n\
+                            * Without hashtags the hashtags of every change are listed")
+    )
+    .subcommand(SubCommand::with_name("rename")
+                .about("Rename a topic on all changes")
+                .arg(Arg::with_name("oldtopicname")
+                     .help("topic to rename")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .arg(Arg::with_name("newtopicname")
+                     .help("new name of the topic")
+                     .required(true)
+                     .takes_value(true)
+                     .index(2)
+                )
+    )
+    .subcommand(SubCommand::with_name("check")
+                .about("Check that all changes of a topic are ready to submit")
+                .arg(Arg::with_name("topicname")
+                     .help("topic name to check")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .after_help("* Reports mergeability, submit type and missing labels per change and per project")
+    )
+    .subcommand(SubCommand::with_name("submit")
+                .about("Submit all changes of a topic")
+                .arg(Arg::with_name("topicname")
+                     .help("topic name to submit")
+                     .required(true)
+                     .takes_value(true)
+                     .index(1)
+                )
+                .arg(Arg::with_name("notify")
+                     .help("Notification hint, without this the gerrit server default is used")
+                     .long("notify")
+                     .short("n")
+                     .takes_value(true)
+                     .possible_values(&["all", "none", "owner", "owner_reviewers"])
+                )
+                .after_help("* With `--dry-run` only the changes submitted together and the checks are printed")
+    )
     .subcommand(SubCommand::with_name("verify")
                 .about("verify topic")
                 .arg(Arg::with_name("topicname")
@@ -166,6 +386,28 @@ pub fn menu<'a, 'b>() -> App<'a, 'b> {
                      .short("m")
                      .long("message")
                 )
+                .arg(Arg::with_name("comment")
+                     .help("inline comment as 'FILE:LINE:MESSAGE', only added to changes which touch FILE. \
+                            LINE 0 is a file comment.")
+                     .takes_value(true)
+                     .multiple(true)
+                     .number_of_values(1)
+                     .short("C")
+                     .long("comment")
+                )
+                .arg(Arg::with_name("tag")
+                     .help("tag for review message, votes and inline comments, eg. 'autogenerated:ci'")
+                     .takes_value(true)
+                     .short("t")
+                     .long("tag")
+                )
+                .arg(Arg::with_name("notify")
+                     .help("Notification hint, without this the gerrit server default is used")
+                     .long("notify")
+                     .short("n")
+                     .takes_value(true)
+                     .possible_values(&["all", "none", "owner", "owner_reviewers"])
+                )
     )
 }
 
@@ -185,6 +427,18 @@ pub fn manage(x: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
         ("reviewer", Some(y)) => { reviewer(y, config) },
         ("abandon", Some(y)) => { abandon(y, config) },
         ("restore", Some(y)) => { restore(y, config) },
+        ("wip", Some(y)) => { change_state(y, config, ChangeState::WorkInProgress) },
+        ("ready", Some(y)) => { change_state(y, config, ChangeState::Ready) },
+        ("private", Some(y)) if y.is_present("unset") => { change_state(y, config, ChangeState::Public) },
+        ("private", Some(y)) => { change_state(y, config, ChangeState::Private) },
+        ("cherry-pick", Some(y)) => { cherry_pick(y, config) },
+        ("revert", Some(y)) => { revert(y, config) },
+        ("move", Some(y)) => { move_topic(y, config) },
+        ("rebase", Some(y)) => { rebase(y, config) },
+        ("hashtag", Some(y)) => { hashtag(y, config) },
+        ("rename", Some(y)) => { rename(y, config) },
+        ("check", Some(y)) => { check(y, config) },
+        ("submit", Some(y)) => { submit(y, config) },
         ("verify", Some(y)) => { verify(y, config) },
         _ => {
             println!("{}", x.usage());
@@ -270,7 +524,7 @@ fn fetch(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     }
 
     let mut gerrit = Gerrit::new(config.get_base_url());
-    fetch_topic(&mut gerrit, topicname, local_branch_name, force, tracking_branch_name, closed)
+    fetch_topic(&mut gerrit, topicname, local_branch_name, force, tracking_branch_name, closed, &config.fetch_transport())
 }
 
 /// fetch history of a topic
@@ -280,7 +534,9 @@ fn history(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     }
 
     let topicname = y.value_of("topicname").expect("no or bad topicname").to_owned();
+    let transport = config.fetch_transport();
     let mut gerrit = Gerrit::new(config.get_base_url());
+    download_commands_hint(&mut gerrit);
 
     let mut changes = gerrit.changes();
     let query_part = vec!(format!("topic:{}", topicname));
@@ -308,6 +564,8 @@ fn history(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
             let topicname = topicname.clone();
             let cistatus = ci.status.clone();
             let dryrun = *config.dry_run();
+            let fetchinfos = revision_fetchinfos(&ci.project, ci._number, &revisioninfo, &transport.scheme);
+            let transport = transport.clone();
 
             children.push(thread::spawn(move || {
                 let is_abandoned = cistatus == entities::ChangeInfoChangeStatus::ABANDONED;
@@ -316,15 +574,13 @@ fn history(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
 
                 let mut outstr = format!("  {} {} ", mark, revision);
 
-                for (fetchtype, fetchinfo) in &revisioninfo.fetch {
-                    if fetchtype.starts_with("http") {
-                        match do_fetch_from_repo(fetchinfo, &topicname, TagOrBranch::Tag, None, false, dryrun) {
-                            Err(x) => {
-                                outstr.push_str(&format!("FAILED: {}", x));
-                            },
-                            Ok(msg) => outstr.push_str(&msg),
-                        };
-                    }
+                for fetchinfo in &fetchinfos {
+                    match do_fetch_from_repo(fetchinfo, &topicname, TagOrBranch::Tag, None, false, dryrun, &transport) {
+                        Err(x) => {
+                            outstr.push_str(&format!("FAILED: {}", x));
+                        },
+                        Ok(msg) => outstr.push_str(&msg),
+                    };
                 }
                 println!("{}", outstr);
             }));
@@ -345,8 +601,53 @@ enum TagOrBranch {
 }
 
 
+/// print a hint if the server has no download-commands plugin
+///
+/// Fetching works without it, the patch set references are computed by `revision_fetchinfos`.
+pub fn download_commands_hint(gerrit: &mut Gerrit) {
+    if !gerrit.plugins().has_download_commands() {
+        info!("download-commands plugin missing, computing refs/changes/NN/<change>/<patch set> and fetching from the local remotes");
+    }
+}
+
+/// returns the fetch information of a revision for fetch scheme `scheme`
+///
+/// The url of a fetch information for `scheme` is used for fetching. Without it the project name
+/// is used as url, then the repository is found via the local remotes and the patch set is fetched
+/// from its `origin` remote. The reference is taken from any other fetch information, or is
+/// computed from the change and patch set number if the server provides no fetch information
+/// (without download-commands plugin).
+fn revision_fetchinfos(project: &str, change_number: u64, revisioninfo: &entities::RevisionInfo, scheme: &str) -> Vec<entities::FetchInfo> {
+    let fetchinfos: Vec<entities::FetchInfo> = revisioninfo.fetch.iter()
+        .filter(|&(fetchtype, _)| fetchtype.starts_with(scheme))
+        .map(|(_, fetchinfo)| fetchinfo.clone())
+        .collect();
+
+    if !fetchinfos.is_empty() {
+        return fetchinfos;
+    }
+
+    let reference = match revisioninfo.fetch.values().next() {
+        Some(fetchinfo) => {
+            debug!("no {} fetch information for {}/{}, use origin remote", scheme, change_number, revisioninfo._number);
+            fetchinfo.reference.clone()
+        },
+        None => {
+            debug!("no fetch information for {}/{} (download-commands plugin missing), compute reference", change_number, revisioninfo._number);
+            revisioninfo.reference.clone()
+                .unwrap_or_else(|| entities::FetchInfo::change_reference(change_number, revisioninfo._number))
+        },
+    };
+
+    vec!(entities::FetchInfo {
+        url: project.into(),
+        reference,
+        commands: None,
+    })
+}
+
 /// fetch via fetchinfo entity a tag or branch, and for branches it can set tracking information.
-fn do_fetch_from_repo(fetchinfo: &entities::FetchInfo, topic: &str, tag_or_branch: TagOrBranch, tracking_branch_name: Option<&str>, force: bool, dryrun: bool) -> GGRResult<String>
+fn do_fetch_from_repo(fetchinfo: &entities::FetchInfo, topic: &str, tag_or_branch: TagOrBranch, tracking_branch_name: Option<&str>, force: bool, dryrun: bool, transport: &config::FetchTransport) -> GGRResult<String>
 {
     debug!("history fetch {:?}", fetchinfo);
 
@@ -370,41 +671,22 @@ fn do_fetch_from_repo(fetchinfo: &entities::FetchInfo, topic: &str, tag_or_branc
     };
 
     /* we have found the rpeository. we can now fetch and tag the revision. */
+    let mut ssh_attempts = 0;
     let mut cb = git2::RemoteCallbacks::new();
     cb.credentials(|url, username, allowed| {
         debug!("credential callback: {} / {:?} / {:?}", url, username, allowed);
 
-        let homefolder = env::home_dir().ok_or(git2::Error::from_str("set HOME environment variable for searching of netrc"))?;
-        let mut netrcfile = PathBuf::new();
-        netrcfile.push(homefolder);
-        netrcfile.push(".netrc");
-
-        if !netrcfile.exists() {
-            return Err(git2::Error::from_str(&format!("cannot find .netrc file at {:?}", netrcfile.as_path())));
+        if allowed.contains(git2::USERNAME) {
+            return git2::Cred::username(&ssh_username(transport, username)?);
         }
 
-        debug!("found .netrc file");
-
-        let f = File::open(netrcfile.as_path())
-            .map_err(|x| { git2::Error::from_str(&format!("file: {}", x)) } )?;
-        let reader = BufReader::new(f);
-
-        let netrc = netrc::Netrc::parse(reader)
-            .map_err(|x| { git2::Error::from_str(&format!("{:?}", x)) } )?;
-
-        let repourl = url::Url::parse(url)
-            .map_err(|x| { git2::Error::from_str(&format!("{}", x)) } )?;
-        for (_, &(ref machinehost, ref machine)) in netrc.hosts.iter().enumerate() {
-            debug!("check machinehost: {}", machinehost);
-            if repourl.host_str() == Some(machinehost) {
-                let password = machine.password.as_ref().ok_or(git2::Error::from_str(&format!("no password for machine {} in netrc", machinehost)))?;
-                let passwordplace = iter::repeat("*").take(password.len()).collect::<String>();
-                debug!("use credentials for login: '{}', with password (hidden): '{}'", &machine.login, passwordplace);
-                return git2::Cred::userpass_plaintext(&machine.login, password);
-            }
+        if allowed.contains(git2::SSH_KEY) {
+            /* libgit2 asks again after a rejected key, this selects the next credential */
+            ssh_attempts += 1;
+            return ssh_credentials(transport, url, username, ssh_attempts);
         }
 
-        Err(git2::Error::from_str(&format!("no correct netrc entry for repository {} found.", url)))
+        netrc_credentials(url)
     });
 
     let mut fetchoptions = git2::FetchOptions::new();
@@ -417,8 +699,15 @@ fn do_fetch_from_repo(fetchinfo: &entities::FetchInfo, topic: &str, tag_or_branc
 
     let workdir = repo.workdir().ok_or(format!("no workdir for '{}' found", repo.path().to_string_lossy()))?
         .file_name().unwrap();
+    /* fetch urls of the server contain a scheme, a bare project name means the `origin` remote */
+    let mut remote = if fetchinfo.url.contains("://") {
+        repo.remote_anonymous(&fetchinfo.url)?
+    } else {
+        repo.find_remote("origin")?
+    };
+    let remote_url = remote.url().unwrap_or(&fetchinfo.url).to_string();
     if !dryrun {
-        match repo.find_remote("origin")?.fetch(&[&refspecs], Some(&mut fetchoptions), Some("")) {
+        match remote.fetch(&[&refspecs], Some(&mut fetchoptions), Some("")) {
             Ok(_) => {
                 if tag_or_branch == TagOrBranch::Branch {
                     if let Ok(mut branch) = repo.find_branch(&name, git2::BranchType::Local) {
@@ -428,7 +717,7 @@ fn do_fetch_from_repo(fetchinfo: &entities::FetchInfo, topic: &str, tag_or_branc
                 Ok(format!("OK, pulled '{}' as {:?} '{}' into {}", fetchinfo.reference, tag_or_branch, name, workdir.to_string_lossy()))
             },
             Err(x) => {
-                Err(GGRError::General(format!("FAILED! Could not pull {} at {} ({})", refspecs, fetchinfo.url, x.message())))
+                Err(GGRError::General(format!("FAILED! Could not pull {} at {} ({})", refspecs, remote_url, x.message())))
             },
         }
     } else {
@@ -436,6 +725,71 @@ fn do_fetch_from_repo(fetchinfo: &entities::FetchInfo, topic: &str, tag_or_branc
     }
 }
 
+/// returns user and password for a http `url` from the `.netrc` file
+fn netrc_credentials(url: &str) -> Result<git2::Cred, git2::Error> {
+    let homefolder = env::home_dir().ok_or(git2::Error::from_str("set HOME environment variable for searching of netrc"))?;
+    let mut netrcfile = PathBuf::new();
+    netrcfile.push(homefolder);
+    netrcfile.push(".netrc");
+
+    if !netrcfile.exists() {
+        return Err(git2::Error::from_str(&format!("cannot find .netrc file at {:?}", netrcfile.as_path())));
+    }
+
+    debug!("found .netrc file");
+
+    let f = File::open(netrcfile.as_path())
+        .map_err(|x| { git2::Error::from_str(&format!("file: {}", x)) } )?;
+    let reader = BufReader::new(f);
+
+    let netrc = netrc::Netrc::parse(reader)
+        .map_err(|x| { git2::Error::from_str(&format!("{:?}", x)) } )?;
+
+    let repourl = url::Url::parse(url)
+        .map_err(|x| { git2::Error::from_str(&format!("{}", x)) } )?;
+    for (_, &(ref machinehost, ref machine)) in netrc.hosts.iter().enumerate() {
+        debug!("check machinehost: {}", machinehost);
+        if repourl.host_str() == Some(machinehost) {
+            let password = machine.password.as_ref().ok_or(git2::Error::from_str(&format!("no password for machine {} in netrc", machinehost)))?;
+            let passwordplace = iter::repeat("*").take(password.len()).collect::<String>();
+            debug!("use credentials for login: '{}', with password (hidden): '{}'", &machine.login, passwordplace);
+            return git2::Cred::userpass_plaintext(&machine.login, password);
+        }
+    }
+
+    Err(git2::Error::from_str(&format!("no correct netrc entry for repository {} found.", url)))
+}
+
+/// returns the user name for ssh remotes, from the remote url or from the configuration
+fn ssh_username(transport: &config::FetchTransport, username_from_url: Option<&str>) -> Result<String, git2::Error> {
+    username_from_url.map(String::from)
+        .or_else(|| transport.ssh_username.clone())
+        .ok_or_else(|| git2::Error::from_str("no user name for ssh remote, set it in the remote url or as `ssh_username` in the config file"))
+}
+
+/// returns ssh credentials for the `attempt`th try
+///
+/// The configured key file is tried first, then the ssh-agent. A key file which cannot be
+/// decrypted is rejected by libgit2, then the ssh-agent is asked.
+fn ssh_credentials(transport: &config::FetchTransport, url: &str, username_from_url: Option<&str>, attempt: u32) -> Result<git2::Cred, git2::Error> {
+    let username = ssh_username(transport, username_from_url)?;
+
+    match (transport.ssh_key.as_ref(), attempt) {
+        (Some(keyfile), 1) => {
+            debug!("use ssh key file {:?} for user {}", keyfile, username);
+            git2::Cred::ssh_key(&username, None, keyfile, transport.ssh_key_passphrase.as_deref())
+        },
+        (Some(_), 2) | (None, 1) => {
+            debug!("use ssh-agent for user {}", username);
+            git2::Cred::ssh_key_from_agent(&username)
+        },
+        _ => {
+            Err(git2::Error::from_str(&format!("ssh authentication for {} failed. An encrypted `ssh_key` needs \
+                                                `ssh_key_passphrase` in the config file or has to be added to the ssh-agent", url)))
+        },
+    }
+}
+
 fn history_extract_projectname<P>(path: &P) -> Option<&str>
 where P: AsRef<Path>
 {
@@ -522,12 +876,63 @@ fn reviewer(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     let mut gerrit = Gerrit::new(config.get_base_url());
     if let Ok(cis) = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", topicname)[..])), None) {
 
+        // remove stale votes for topic
+        if let Some(ref deletevotes) = y.values_of_lossy("delete-vote") {
+            let notify = y.value_of("notify");
+            for deletevote in deletevotes {
+                let mut reviewerlabel = deletevote.rsplitn(2, ':');
+                let label = reviewerlabel.next().unwrap_or("").trim();
+                let reviewer = reviewerlabel.next().unwrap_or("").trim();
+                if reviewer.is_empty() || label.is_empty() {
+                    return Err(GGRError::General(format!("Wrong vote '{}', use 'REVIEWER:LABEL'", deletevote)));
+                }
+
+                for ci in &cis {
+                    let changes = gerrit.changes();
+                    match changes.list_votes(&ci.id, reviewer) {
+                        Ok(ref votes) if votes.get(label).is_some_and(|v| *v != 0) => {
+                            match changes.delete_vote(&ci.id, reviewer, label, notify) {
+                                Ok(_) => println!("* {:5.5} [{:20.20}] vote {:+} of '{}' for {} removed", ci.change_id, ci.subject, votes[label], reviewer, label),
+                                Err(x) => println!("* {:5.5} [{:20.20}] vote of '{}' for {} not removed: {}", ci.change_id, ci.subject, reviewer, label, x),
+                            }
+                        },
+                        Ok(_) => println!("* {:5.5} [{:20.20}] no vote of '{}' for {}", ci.change_id, ci.subject, reviewer, label),
+                        Err(GGRError::GerritApiError(GerritError::GerritApi(404, _))) => println!("* {:5.5} [{:20.20}] '{}' is no reviewer", ci.change_id, ci.subject, reviewer),
+                        Err(x) => println!("* {:5.5} [{:20.20}] problem to list votes of '{}': {}", ci.change_id, ci.subject, reviewer, x),
+                    }
+                }
+            }
+
+            if y.values_of_lossy("reviewers").is_none() {
+                return Ok(());
+            }
+        }
+
         let mut children = Vec::new();
 
         // manipulate reviewer for topic
         if let Some(ref reviewerlist) = y.values_of_lossy("reviewers") {
+            let confirmed = y.is_present("confirm");
+            let reviewerlist = match cis.first() {
+                Some(ci) => resolve_reviewers(&mut gerrit, &ci.id, reviewerlist, confirmed)?,
+                None => reviewerlist.clone(),
+            };
+
+            let reviewerinput = entities::ReviewerInput {
+                state: Some(if y.is_present("cc") { entities::ReviewerState::CC } else { entities::ReviewerState::REVIEWER }),
+                confirmed: if confirmed { Some(true) } else { None },
+                notify: Changes::build_notify(y.value_of("notify"))?,
+                notify_details: y.values_of_lossy("notify-to").map(|accounts| {
+                    let mut details = HashMap::new();
+                    details.insert(entities::RecipientType::TO, entities::NotifyInfo { accounts: Some(accounts) });
+                    details
+                }),
+                .. Default::default()
+            };
+
             for ci in cis {
                 let reviewerlist = reviewerlist.clone();
+                let reviewerinput = reviewerinput.clone();
                 let mut gerrit = gerrit.clone();
                 children.push(thread::spawn(move || {
                     for reviewer in reviewerlist {
@@ -566,28 +971,44 @@ fn reviewer(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
                                 println!("* {:5.5} [{:20.20}] reviewer '{}' removed", ci.change_id, ci.subject, reviewer);
                             };
                         } else {
-                            match gerrit.changes().add_reviewer(&ci.change_id, &reviewer) {
+                            let reviewerinput = entities::ReviewerInput {
+                                reviewer: reviewer.clone(),
+                                .. reviewerinput.clone()
+                            };
+                            match gerrit.changes().add_reviewer(&ci.change_id, &reviewerinput) {
                                 Ok(addreviewerresult) => {
 
-                                    match addreviewerresult.reviewers {
-                                        Some(reviewerret) => {
-                                            for r in reviewerret {
-                                                println!("* {:5.5} [{:20.20}] reviewer {}, {}, {}: added",
-                                                         ci.change_id,
-                                                         ci.subject,
-                                                         r.name.unwrap_or_else(|| "unkown name".into()),
-                                                         r.email.unwrap_or_else(|| "unkown mail".into()),
-                                                         r._account_id.unwrap_or(99999999));
-                                            }
-                                        },
-                                        None => {
-                                            println!("* {:5.5} [{:20.20}] reviewer '{}' not added: {}",
+                                    if addreviewerresult.confirm.unwrap_or(false) {
+                                        println!("* {:5.5} [{:20.20}] reviewer '{}' not added: needs confirmation, use --confirm",
+                                                 ci.change_id,
+                                                 ci.subject,
+                                                 reviewer);
+                                        continue;
+                                    }
+
+                                    let added = addreviewerresult.reviewers.map(|r| ("reviewer", r))
+                                        .into_iter()
+                                        .chain(addreviewerresult.ccs.map(|r| ("cc", r)))
+                                        .collect::<Vec<_>>();
+
+                                    if added.is_empty() {
+                                        println!("* {:5.5} [{:20.20}] reviewer '{}' not added: {}",
+                                                 ci.change_id,
+                                                 ci.subject,
+                                                 reviewer,
+                                                 addreviewerresult.error.unwrap_or_else(|| "No error message from gerrit server provided".into()));
+                                    }
+                                    for (state, reviewerret) in added {
+                                        for r in reviewerret {
+                                            println!("* {:5.5} [{:20.20}] {} {}, {}, {}: added",
                                                      ci.change_id,
                                                      ci.subject,
-                                                     reviewer,
-                                                     addreviewerresult.error.unwrap_or_else(|| "No error message from gerrit server provided".into()));
-                                        },
-                                    };
+                                                     state,
+                                                     r.name.unwrap_or_else(|| "unkown name".into()),
+                                                     r.email.unwrap_or_else(|| "unkown mail".into()),
+                                                     r._account_id.unwrap_or(99999999));
+                                        }
+                                    }
                                 },
                                 Err(e) => {
                                     println!("Problem to add '{}' as reviewer: {}", reviewer, e);
@@ -711,75 +1132,821 @@ fn restore(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     Ok(())
 }
 
-/// verify a topic
-fn verify(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
-    let topicname = y.value_of("topicname").expect("problem with topicname");
-    let message = y.value_of("message");
+/// cherry pick a topic to another branch
+///
+/// The changes are cherry picked in dependency order. A change which depends on an other change
+/// of the topic is cherry picked on top of the already cherry picked parent change.
+fn cherry_pick(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let topicname = y.value_of("topicname").expect("problem with topic name");
+    let branch = y.value_of("branch").expect("problem with branch name");
+    let newtopicname = y.value_of("newtopicname");
+    let dryrun = *config.dry_run();
 
-    let review = {
-        let mut r = entities::ReviewInfo {
-            labels: HashMap::new(),
-        };
+    debug!("cherry-pick topic:{}, branch:{}, newtopic:{:?}", topicname, branch, newtopicname);
 
-        if let Some(label) = y.value_of("label") {
-            if label.contains(':') {
-                let mut labelvalue = label.splitn(2, ':');
-                let label = labelvalue.next().unwrap_or("").trim();
-                let value = labelvalue.next().unwrap_or("").trim();
+    let mut gerrit = Gerrit::new(config.get_base_url());
 
-                if ! label.is_empty() && ! value.is_empty() {
-                    let value = match value {
-                        "~2" | "-2" => -2,
-                        "~1" | "-1" => -1,
-                        "0" | "+0" | "-0" => 0,
-                        "1" | "+1" => 1,
-                        "2" | "+2" => 2,
-                        _ => return Err(GGRError::General("Wrong value for label. Only support for (-2,-1,0,1,2)".into())),
-                    };
-                    r.labels.entry(label.into()).or_insert(value);
-                }
-            }
-        };
+    let cis = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", topicname)[..], "-status:abandoned")), Some(vec!("CURRENT_REVISION", "CURRENT_COMMIT")))?;
+    if cis.is_empty() {
+        println!("topic '{}' not found", topicname);
+        return Ok(());
+    }
 
-        if let Some(codereview) = y.value_of("code-review") {
-            let codereview = match codereview {
-                "~2" => -2,
-                "~1" => -1,
-                "0" => 0,
-                "1" => 1,
-                "2" => 2,
-                _ => return Err(GGRError::General("Wrong code-review parameter".into())),
-            };
-            r.labels.entry("Code-Review".into()).or_insert(codereview);
+    // maps the picked revisions to the revisions of the newly created changes
+    let mut picked: HashMap<String, String> = HashMap::new();
+    for ci in sort_by_dependency(&cis) {
+        let (revision, commit) = match current_revision_commit(ci) {
+            Some(x) => x,
+            None => {
+                println!("* {:5.5} [{:20.20}] {}: no current revision set. No work on this changeid", ci.change_id, ci.subject, ci.project);
+                continue;
+            },
         };
 
-        if r.labels.is_empty() {
-            None
-        } else {
-            Some(r)
+        if dryrun {
+            println!("* {:5.5} [{:20.20}] {}: (dry-run) cherry-picked to '{}'", ci.change_id, ci.subject, ci.project, branch);
+            continue;
         }
-    };
-
-    let mut gerrit = Gerrit::new(config.get_base_url());
-    let mut changes = gerrit.changes();
 
-    if let Ok(changeinfos) = changes.query_changes(Some(vec!(&format!("topic:{}", topicname)[..])), Some(vec!("CURRENT_REVISION"))) {
-        /* overall review result for the commit */
-        let mut overall_review: HashMap<String /*label*/, (i8,i8) /* min/max */> = HashMap::new();
-
-        for ci in changeinfos {
-            debug!("{:?}", ci);
-            let (id, changeid, revision, subject) = (
-                ci.id.clone(),
-                ci.change_id.clone(),
-                ci.current_revision.unwrap_or_else(|| "".into()),
-                ci.subject
-            );
+        let base = commit.parents.as_ref()
+            .and_then(|parents| parents.iter().filter_map(|p| picked.get(&p.commit)).next())
+            .cloned();
 
-            let changes = gerrit.changes();
+        let cherrypickinput = entities::CherryPickInput {
+            message: commit.message.clone(),
+            destination: branch.into(),
+            base,
+            parent: None,
+        };
 
-            if message.is_none() && review.is_none() {
-                // neither review or message is set, we retrieve review information
+        let mut changes = gerrit.changes();
+        match changes.cherry_pick_revision(&ci.id, revision, &cherrypickinput) {
+            Ok(newci) => {
+                print!("* {:5.5} [{:20.20}] {}: cherry-picked to '{}' as {}", ci.change_id, ci.subject, ci.project, branch, newci._number);
+                if let Some(newtopicname) = newtopicname {
+                    if let Err(x) = changes.set_topic(&newci.id, Some(newtopicname)) {
+                        print!(", topic not set: {}", x);
+                    }
+                }
+                println!();
+
+                if let Ok(newci) = changes.get_change(&newci.id, Some(vec!("CURRENT_REVISION"))) {
+                    if let Some(newrevision) = newci.current_revision {
+                        picked.insert(revision.clone(), newrevision);
+                    }
+                }
+            },
+            Err(x) => {
+                println!("* {:5.5} [{:20.20}] {}: not cherry-picked: {}", ci.change_id, ci.subject, ci.project, x);
+            },
+        };
+    }
+
+    Ok(())
+}
+
+/// revert a merged topic
+///
+/// The changes are reverted in reverse dependency order, the newest change first.
+fn revert(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let topicname = y.value_of("topicname").expect("problem with topic name");
+    let message = y.value_of("message");
+    let newtopicname = y.value_of("newtopicname");
+    let dryrun = *config.dry_run();
+
+    debug!("revert topic:{}, message:{}", topicname, message.unwrap_or(""));
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+
+    let cis = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", topicname)[..], "status:merged")), Some(vec!("CURRENT_REVISION", "CURRENT_COMMIT")))?;
+    if cis.is_empty() {
+        println!("no merged changes for topic '{}' found", topicname);
+        return Ok(());
+    }
+
+    let mut sorted = sort_by_dependency(&cis);
+    sorted.reverse();
+
+    for ci in sorted {
+        if dryrun {
+            println!("* {:5.5} [{:20.20}] {}: (dry-run) reverted", ci.change_id, ci.subject, ci.project);
+            continue;
+        }
+
+        let changes = gerrit.changes();
+        match changes.revert_change(&ci.id, message) {
+            Ok(newci) => {
+                print!("* {:5.5} [{:20.20}] {}: reverted with {}", ci.change_id, ci.subject, ci.project, newci._number);
+                if let Some(newtopicname) = newtopicname {
+                    if let Err(x) = changes.set_topic(&newci.id, Some(newtopicname)) {
+                        print!(", topic not set: {}", x);
+                    }
+                }
+                println!();
+            },
+            Err(x) => {
+                println!("* {:5.5} [{:20.20}] {}: not reverted: {}", ci.change_id, ci.subject, ci.project, x);
+            },
+        };
+    }
+
+    Ok(())
+}
+
+/// move a topic to another branch
+fn move_topic(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let topicname = y.value_of("topicname").expect("problem with topic name");
+    let branch = y.value_of("branch").expect("problem with branch name");
+    let message = y.value_of("message");
+    let dryrun = *config.dry_run();
+
+    debug!("move topic:{}, branch:{}, message:{}", topicname, branch, message.unwrap_or(""));
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+
+    let cis = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", topicname)[..], "status:open")), None)?;
+    if cis.is_empty() {
+        println!("no open changes for topic '{}' found", topicname);
+        return Ok(());
+    }
+
+    for ci in cis {
+        if dryrun {
+            println!("* {:5.5} [{:20.20}] {}: (dry-run) moved from '{}' to '{}'", ci.change_id, ci.subject, ci.project, ci.branch, branch);
+            continue;
+        }
+
+        match gerrit.changes().move_change(&ci.id, branch, message) {
+            Ok(_) => {
+                println!("* {:5.5} [{:20.20}] {}: moved from '{}' to '{}'", ci.change_id, ci.subject, ci.project, ci.branch, branch);
+            },
+            Err(x) => {
+                println!("* {:5.5} [{:20.20}] {}: not moved: {}", ci.change_id, ci.subject, ci.project, x);
+            },
+        };
+    }
+
+    Ok(())
+}
+
+/// rebase a topic
+///
+/// The changes are rebased in dependency order. A change without a parent change in the topic is
+/// rebased on the tip of his branch, all other changes are rebased on the new patch set of their
+/// parent change. Changes with a not rebased parent change are skipped.
+fn rebase(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let topicname = y.value_of("topicname").expect("problem with topic name");
+    let dryrun = *config.dry_run();
+
+    debug!("rebase topic:{}", topicname);
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+
+    let cis = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", topicname)[..], "status:open")), Some(vec!("CURRENT_REVISION", "CURRENT_COMMIT")))?;
+    if cis.is_empty() {
+        println!("no open changes for topic '{}' found", topicname);
+        return Ok(());
+    }
+
+    let topic_revisions: Vec<&String> = cis.iter().filter_map(|ci| ci.current_revision.as_ref()).collect();
+
+    // maps the old revisions to the revisions after rebase, `None` if the rebase failed
+    let mut rebased: HashMap<String, Option<String>> = HashMap::new();
+    for ci in sort_by_dependency(&cis) {
+        let (revision, commit) = match current_revision_commit(ci) {
+            Some(x) => x,
+            None => {
+                println!("* {:5.5} [{:20.20}] {}: no current revision set. No work on this changeid", ci.change_id, ci.subject, ci.project);
+                continue;
+            },
+        };
+
+        // the parent change of the topic, `None` if the change is the first of a chain
+        let parent = commit.parents.as_ref()
+            .and_then(|parents| parents.iter().map(|p| &p.commit).find(|p| topic_revisions.contains(p)));
+
+        let base = match parent {
+            Some(parent) => {
+                match rebased.get(parent) {
+                    Some(Some(newparent)) => Some(newparent.clone()),
+                    _ => {
+                        println!("* {:5.5} [{:20.20}] {}: skipped, parent change not rebased", ci.change_id, ci.subject, ci.project);
+                        rebased.insert(revision.clone(), None);
+                        continue;
+                    },
+                }
+            },
+            None => None,
+        };
+
+        if dryrun {
+            println!("* {:5.5} [{:20.20}] {}: (dry-run) rebased on {}", ci.change_id, ci.subject, ci.project, base.as_ref().map_or(&ci.branch, |b| b));
+            rebased.insert(revision.clone(), Some(revision.clone()));
+            continue;
+        }
+
+        match gerrit.changes().rebase_change(&ci.id, base.as_ref().map(|b| &b[..])) {
+            Ok(newci) => {
+                println!("* {:5.5} [{:20.20}] {}: rebased", ci.change_id, ci.subject, ci.project);
+                rebased.insert(revision.clone(), Some(newci.current_revision.unwrap_or_else(|| revision.clone())));
+            },
+            Err(GGRError::GerritApiError(GerritError::GerritApi(409, ref text))) if text.contains("up to date") => {
+                println!("* {:5.5} [{:20.20}] {}: already up to date", ci.change_id, ci.subject, ci.project);
+                rebased.insert(revision.clone(), Some(revision.clone()));
+            },
+            Err(x) => {
+                println!("* {:5.5} [{:20.20}] {}: not rebased: {}", ci.change_id, ci.subject, ci.project, x);
+                rebased.insert(revision.clone(), None);
+            },
+        };
+    }
+
+    Ok(())
+}
+
+/// rename a topic
+fn rename(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let oldtopicname = y.value_of("oldtopicname").expect("problem with old topic name");
+    let newtopicname = y.value_of("newtopicname").expect("problem with new topic name");
+    let dryrun = *config.dry_run();
+
+    debug!("rename topic:{} -> {}", oldtopicname, newtopicname);
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+
+    let cis = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", oldtopicname)[..])), None)?;
+    if cis.is_empty() {
+        println!("topic '{}' not found", oldtopicname);
+        return Ok(());
+    }
+
+    let mut renamed = 0;
+    for ci in &cis {
+        if dryrun {
+            println!("* {:5.5} [{:20.20}] {}: (dry-run) renamed to '{}'", ci.change_id, ci.subject, ci.project, newtopicname);
+            renamed += 1;
+            continue;
+        }
+
+        match gerrit.changes().set_topic(&ci.id, Some(newtopicname)) {
+            Ok(_) => {
+                println!("* {:5.5} [{:20.20}] {}: renamed to '{}'", ci.change_id, ci.subject, ci.project, newtopicname);
+                renamed += 1;
+            },
+            Err(x) => {
+                println!("* {:5.5} [{:20.20}] {}: not renamed: {}", ci.change_id, ci.subject, ci.project, x);
+            },
+        };
+    }
+
+    println!("\n{} of {} changes renamed from '{}' to '{}'", renamed, cis.len(), oldtopicname, newtopicname);
+
+    Ok(())
+}
+
+/// list, add or remove hashtags of all changes of a topic
+///
+/// With `--query` the open changes with a hashtag are listed grouped by their topic.
+fn hashtag(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let dryrun = *config.dry_run();
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+
+    if let Some(hashtag) = y.value_of("query") {
+        let cis = gerrit.changes().query_changes(Some(vec!(&format!("hashtag:{}", hashtag)[..], "status:open")), None)?;
+        if cis.is_empty() {
+            println!("no open changes with hashtag '{}' found", hashtag);
+            return Ok(());
+        }
+
+        let mut topics: HashMap<String, Vec<&entities::ChangeInfo>> = HashMap::new();
+        for ci in &cis {
+            topics.entry(ci.topic.clone().unwrap_or_default()).or_default().push(ci);
+        }
+
+        let mut names: Vec<&String> = topics.keys().collect();
+        names.sort();
+        for name in names {
+            println!("{}:", if name.is_empty() { "(no topic)" } else { name });
+            for ci in &topics[name] {
+                println!("* {:5.5} [{:20.20}] {}", ci.change_id, ci.subject, ci.project);
+            }
+        }
+
+        return Ok(());
+    }
+
+    /* the topic name is the last value, all values before are hashtags */
+    let mut hashtags = y.values_of_lossy("hashtags").unwrap_or_default();
+    let topicname = hashtags.pop().expect("problem with topic name");
+    let (add, remove) = split_hashtags(&hashtags);
+
+    let cis = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", topicname)[..])), None)?;
+    if cis.is_empty() {
+        println!("topic '{}' not found", topicname);
+        return Ok(());
+    }
+
+    for ci in &cis {
+        if add.is_empty() && remove.is_empty() {
+            println!("* {:5.5} [{:20.20}] {}: {}", ci.change_id, ci.subject, ci.project, ci.hashtags.clone().unwrap_or_default().join(", "));
+            continue;
+        }
+
+        if dryrun {
+            println!("* {:5.5} [{:20.20}] {}: (dry-run) add [{}], remove [{}]", ci.change_id, ci.subject, ci.project, add.join(", "), remove.join(", "));
+            continue;
+        }
+
+        match gerrit.changes().set_hashtags(&ci.id, add.clone(), remove.clone()) {
+            Ok(hashtags) => println!("* {:5.5} [{:20.20}] {}: {}", ci.change_id, ci.subject, ci.project, hashtags.join(", ")),
+            Err(x) => println!("* {:5.5} [{:20.20}] {}: hashtags not changed: {}", ci.change_id, ci.subject, ci.project, x),
+        };
+    }
+
+    Ok(())
+}
+
+/// splits `hashtags` in hashtags to add (`+foo` or `foo`) and to remove (`-foo`)
+pub fn split_hashtags(hashtags: &[String]) -> (Vec<&str>, Vec<&str>) {
+    let mut add = Vec::new();
+    let mut remove = Vec::new();
+
+    for hashtag in hashtags {
+        let hashtag = hashtag.trim();
+        match hashtag.strip_prefix('-') {
+            Some(hashtag) => remove.push(hashtag),
+            None => add.push(hashtag.strip_prefix('+').unwrap_or(hashtag)),
+        }
+    }
+
+    add.retain(|h| !h.is_empty());
+    remove.retain(|h| !h.is_empty());
+    (add, remove)
+}
+
+/// resolves partial names, usernames or mail addresses of `reviewers` to exact accounts or groups
+///
+/// The suggestions of the gerrit server for the change `changeid` are used. Reviewers to remove
+/// (leading '~') are not resolved. Unknown and ambiguous reviewers are reported together as error.
+fn resolve_reviewers(gerrit: &mut Gerrit, changeid: &str, reviewers: &[String], confirmed: bool) -> GGRResult<Vec<String>> {
+    let mut resolved = Vec::new();
+    let mut problems = Vec::new();
+
+    for reviewer in reviewers {
+        if reviewer.starts_with('~') {
+            resolved.push(reviewer.clone());
+            continue;
+        }
+
+        let suggestions = gerrit.changes().suggest_reviewers(changeid, reviewer, Some(10))?;
+        match pick_reviewer(reviewer, &suggestions) {
+            Ok(sri) => {
+                let (id, description) = describe_suggestion(reviewer, sri);
+                if sri.confirm.unwrap_or(false) && !confirmed {
+                    problems.push(format!("reviewer '{}' ({}, {} members) needs confirmation, use --confirm", reviewer, description, sri.count.unwrap_or(0)));
+                    continue;
+                }
+                if id != *reviewer {
+                    println!("reviewer '{}' resolved to {}", reviewer, description);
+                }
+                resolved.push(id);
+            },
+            Err(x) => problems.push(x),
+        }
+    }
+
+    if !problems.is_empty() {
+        return Err(GGRError::General(problems.join("\n")));
+    }
+
+    Ok(resolved)
+}
+
+/// returns the id and a description of the account or group of a suggestion for `reviewer`
+fn describe_suggestion(reviewer: &str, sri: &entities::SuggestedReviewerInfo) -> (String, String) {
+    if let Some(ref account) = sri.account {
+        let id = account._account_id.map(|id| id.to_string())
+            .or_else(|| account.email.clone())
+            .or_else(|| account.username.clone())
+            .unwrap_or_else(|| reviewer.into());
+        let description = format!("{} <{}>", account.name.clone().unwrap_or_default(), account.email.clone().unwrap_or_default());
+        (id, description)
+    } else if let Some(ref group) = sri.group {
+        (group.id.clone(), format!("group {}", group.name))
+    } else {
+        (reviewer.into(), reviewer.into())
+    }
+}
+
+/// picks the matching suggestion for `reviewer`
+///
+/// A suggestion with exactly matching mail address, username or name is preferred, otherwise the
+/// suggestion needs to be unique.
+pub fn pick_reviewer<'a>(reviewer: &str, suggestions: &'a [entities::SuggestedReviewerInfo]) -> Result<&'a entities::SuggestedReviewerInfo, String> {
+    let lower = reviewer.to_lowercase();
+    let is_exact = |sri: &&entities::SuggestedReviewerInfo| -> bool {
+        let matches = |x: &Option<String>| x.as_ref().is_some_and(|x| x.to_lowercase() == lower);
+        match (&sri.account, &sri.group) {
+            (Some(account), _) => matches(&account.email) || matches(&account.username) || matches(&account.name),
+            (_, Some(group)) => group.name.to_lowercase() == lower,
+            _ => false,
+        }
+    };
+
+    let exact: Vec<&entities::SuggestedReviewerInfo> = suggestions.iter().filter(is_exact).collect();
+    if exact.len() == 1 {
+        return Ok(exact[0]);
+    }
+
+    match suggestions.len() {
+        0 => Err(format!("no account or group found for reviewer '{}'", reviewer)),
+        1 => Ok(&suggestions[0]),
+        _ => {
+            let candidates: Vec<String> = suggestions.iter().map(|s| describe_suggestion(reviewer, s).1).collect();
+            Err(format!("reviewer '{}' is ambiguous: {}", reviewer, candidates.join(", ")))
+        },
+    }
+}
+
+/// state of a change set via `ggr topic wip|ready|private`
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ChangeState {
+    WorkInProgress,
+    Ready,
+    Private,
+    Public,
+}
+
+impl ChangeState {
+    /// returns `true` if `ci` is already in this state
+    fn is_set(&self, ci: &entities::ChangeInfo) -> bool {
+        match *self {
+            ChangeState::WorkInProgress => ci.work_in_progress.unwrap_or(false),
+            ChangeState::Ready => !ci.work_in_progress.unwrap_or(false),
+            ChangeState::Private => ci.is_private.unwrap_or(false),
+            ChangeState::Public => !ci.is_private.unwrap_or(false),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match *self {
+            ChangeState::WorkInProgress => "work in progress",
+            ChangeState::Ready => "ready for review",
+            ChangeState::Private => "private",
+            ChangeState::Public => "not private",
+        }
+    }
+}
+
+/// set all open changes of a topic to work in progress, ready for review, private or public
+///
+/// Changes already in this state are skipped. Failed changes are reported at the end.
+fn change_state(y: &clap::ArgMatches, config: &config::Config, state: ChangeState) -> GGRResult<()> {
+    let topicname = y.value_of("topicname").expect("problem with topic name");
+    let message = y.value_of("message");
+    let dryrun = *config.dry_run();
+
+    debug!("change state topic:{}, state:{:?}, message:{:?}", topicname, state, message);
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+
+    let cis = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", topicname)[..], "status:open")), None)?;
+    if cis.is_empty() {
+        println!("no open changes for topic '{}' found", topicname);
+        return Ok(());
+    }
+
+    let mut failed = Vec::new();
+    for ci in &cis {
+        if state.is_set(ci) {
+            println!("* {:5.5} [{:20.20}] {}: already {}", ci.change_id, ci.subject, ci.project, state.name());
+            continue;
+        }
+
+        if dryrun {
+            println!("* {:5.5} [{:20.20}] {}: (dry-run) marked as {}", ci.change_id, ci.subject, ci.project, state.name());
+            continue;
+        }
+
+        let changes = gerrit.changes();
+        let res = match state {
+            ChangeState::WorkInProgress => changes.set_work_in_progress(&ci.id, message),
+            ChangeState::Ready => changes.set_ready_for_review(&ci.id, message),
+            ChangeState::Private => changes.mark_private(&ci.id, message),
+            ChangeState::Public => changes.unmark_private(&ci.id, message),
+        };
+
+        match res {
+            Ok(_) => println!("* {:5.5} [{:20.20}] {}: marked as {}", ci.change_id, ci.subject, ci.project, state.name()),
+            Err(x) => {
+                println!("* {:5.5} [{:20.20}] {}: not marked as {}: {}", ci.change_id, ci.subject, ci.project, state.name(), x);
+                failed.push(ci);
+            },
+        };
+    }
+
+    if !failed.is_empty() {
+        println!("\nFailed:");
+        for ci in &failed {
+            println!("* {:5.5} [{:20.20}] {}", ci.change_id, ci.subject, ci.project);
+        }
+        return Err(GGRError::General(format!("{} of {} changes of topic '{}' not marked as {}", failed.len(), cis.len(), topicname, state.name())));
+    }
+
+    Ok(())
+}
+
+/// check that a topic is ready to submit
+///
+/// For every open change of the topic the mergeability, the submit type and the missing or
+/// blocking labels are printed. Afterwards a summary per project is printed.
+fn check(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let topicname = y.value_of("topicname").expect("problem with topic name");
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+
+    let cis = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", topicname)[..], "status:open")), Some(vec!("LABELS", "SUBMITTABLE")))?;
+    if cis.is_empty() {
+        println!("no open changes for topic '{}' found", topicname);
+        return Ok(());
+    }
+
+    // project -> (count of changes, count of not ready changes)
+    let mut projects: HashMap<String, (usize, usize)> = HashMap::new();
+
+    println!("Changes:");
+    for ci in &cis {
+        let mut status = Vec::new();
+
+        let submit_type = match gerrit.changes().get_mergeable(&ci.id, "current", false) {
+            Ok(mi) => {
+                if !mi.mergeable {
+                    match mi.conflicts {
+                        Some(ref conflicts) if !conflicts.is_empty() => status.push(format!("conflicts in {}", conflicts.join(", "))),
+                        _ => status.push("not mergeable".into()),
+                    }
+                }
+                mi.submit_type
+            },
+            Err(x) => {
+                status.push(format!("mergeable unknown ({})", x));
+                ci.submit_type.clone().unwrap_or_else(|| "?".into())
+            },
+        };
+
+        let (missing, blocking) = label_state(ci);
+        if !missing.is_empty() {
+            status.push(format!("missing {}", missing.join(", ")));
+        }
+        if !blocking.is_empty() {
+            status.push(format!("blocked by {}", blocking.join(", ")));
+        }
+        if status.is_empty() && !ci.submittable.unwrap_or(false) {
+            status.push("not submittable".into());
+        }
+
+        let project = projects.entry(ci.project.clone()).or_insert((0, 0));
+        project.0 += 1;
+
+        if status.is_empty() {
+            println!("* {:5.5} [{:20.20}] {} ({}): OK", ci.change_id, ci.subject, ci.project, submit_type);
+        } else {
+            project.1 += 1;
+            println!("* {:5.5} [{:20.20}] {} ({}): {}", ci.change_id, ci.subject, ci.project, submit_type, status.join("; "));
+        }
+    }
+
+    println!("\nProjects:");
+    let mut names: Vec<&String> = projects.keys().collect();
+    names.sort();
+    let mut problems = 0;
+    for name in names {
+        let (count, notready) = projects[name];
+        problems += notready;
+        if notready == 0 {
+            println!("* {}: ready ({} changes)", name, count);
+        } else {
+            println!("* {}: {} of {} changes not ready", name, notready, count);
+        }
+    }
+
+    if problems > 0 {
+        return Err(GGRError::General(format!("{} of {} changes of topic '{}' are not ready", problems, cis.len(), topicname)));
+    }
+
+    println!("\ntopic '{}' is ready to submit", topicname);
+    Ok(())
+}
+
+/// returns the missing and the blocking labels of a change
+///
+/// Needs the `LABELS` feature in the query. Optional labels are never missing.
+pub fn label_state(ci: &entities::ChangeInfo) -> (Vec<String>, Vec<String>) {
+    let mut missing = Vec::new();
+    let mut blocking = Vec::new();
+
+    if let Some(ref labels) = ci.labels {
+        for (name, li) in labels {
+            if li.blocking.unwrap_or(false) || li.rejected.is_some() {
+                blocking.push(name.clone());
+            } else if !li.optional.unwrap_or(false) && li.approved.is_none() {
+                missing.push(name.clone());
+            }
+        }
+    }
+
+    missing.sort();
+    blocking.sort();
+    (missing, blocking)
+}
+
+/// submit a topic
+///
+/// First all changes which are submitted together with the changes of the topic are printed and
+/// every change of the topic is checked to be submittable and mergeable. Only if all checks are
+/// passed, the changes are submitted.
+fn submit(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let topicname = y.value_of("topicname").expect("problem with topic name");
+    let notify = y.value_of("notify");
+    let dryrun = *config.dry_run();
+
+    debug!("submit topic:{}, notify:{:?}", topicname, notify);
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+
+    let cis = gerrit.changes().query_changes(Some(vec!(&format!("topic:{}", topicname)[..], "status:open")), Some(vec!("SUBMITTABLE")))?;
+    if cis.is_empty() {
+        println!("no open changes for topic '{}' found", topicname);
+        return Ok(());
+    }
+
+    // all changes which are submitted together with a change of the topic
+    let mut together: HashMap<String /* id */, Vec<String> /* ids */> = HashMap::new();
+    let mut outside_topic: HashMap<String /* id */, entities::ChangeInfo> = HashMap::new();
+    let mut non_visible = 0;
+
+    println!("Submitted together:");
+    for ci in &cis {
+        let sti = gerrit.changes().submitted_together(&ci.id, None)?;
+        /* every change reports the same set, the counts overlap */
+        non_visible = cmp::max(non_visible, sti.non_visible_changes.unwrap_or(0));
+
+        let mut ids = Vec::new();
+        for stci in sti.changes {
+            ids.push(stci.id.clone());
+            if !cis.iter().any(|x| x.id == stci.id) {
+                outside_topic.entry(stci.id.clone()).or_insert(stci);
+            }
+        }
+        together.insert(ci.id.clone(), ids);
+
+        println!("* {:5.5} [{:20.20}] {}", ci.change_id, ci.subject, ci.project);
+    }
+    for ci in outside_topic.values() {
+        println!("* {:5.5} [{:20.20}] {} (not in topic '{}')", ci.change_id, ci.subject, ci.project, ci.topic.clone().unwrap_or_default());
+    }
+    if non_visible > 0 {
+        println!("* ... and {} changes which are not visible to you", non_visible);
+    }
+
+    println!("\nChecks:");
+    let mut problems = 0;
+    for ci in &cis {
+        let submittable = ci.submittable.unwrap_or(false);
+        let mergeable = ci.mergeable.unwrap_or(true);
+
+        let mut status = Vec::new();
+        if !submittable {
+            status.push("not submittable");
+        }
+        if !mergeable {
+            status.push("not mergeable");
+        }
+
+        if status.is_empty() {
+            println!("* {:5.5} [{:20.20}] {}: OK", ci.change_id, ci.subject, ci.project);
+        } else {
+            problems += 1;
+            println!("* {:5.5} [{:20.20}] {}: {}", ci.change_id, ci.subject, ci.project, status.join(", "));
+        }
+    }
+
+    if problems > 0 {
+        return Err(GGRError::General(format!("{} of {} changes of topic '{}' can not be submitted", problems, cis.len(), topicname)));
+    }
+
+    if dryrun {
+        println!("\n(dry-run) topic '{}' not submitted", topicname);
+        return Ok(());
+    }
+
+    println!("\nSubmit:");
+    let mut submitted: Vec<String> = Vec::new();
+    for ci in &cis {
+        if submitted.contains(&ci.id) {
+            println!("* {:5.5} [{:20.20}] {}: submitted together with a previous change", ci.change_id, ci.subject, ci.project);
+            continue;
+        }
+
+        match gerrit.changes().submit_change(&ci.id, notify) {
+            Ok(ciret) => {
+                println!("* {:5.5} [{:20.20}] {}: submitted ({:?})", ci.change_id, ci.subject, ci.project, ciret.status);
+                submitted.push(ci.id.clone());
+                if let Some(ids) = together.get(&ci.id) {
+                    submitted.extend(ids.iter().cloned());
+                }
+            },
+            Err(x) => {
+                println!("* {:5.5} [{:20.20}] {}: not submitted: {}", ci.change_id, ci.subject, ci.project, x);
+            },
+        };
+    }
+
+    Ok(())
+}
+
+/// verify a topic
+fn verify(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
+    let topicname = y.value_of("topicname").expect("problem with topicname");
+    let message = y.value_of("message");
+
+    let labels = {
+        let mut r = entities::ReviewInfo {
+            labels: HashMap::new(),
+        };
+
+        if let Some(label) = y.value_of("label") {
+            if label.contains(':') {
+                let mut labelvalue = label.splitn(2, ':');
+                let label = labelvalue.next().unwrap_or("").trim();
+                let value = labelvalue.next().unwrap_or("").trim();
+
+                if ! label.is_empty() && ! value.is_empty() {
+                    let value = match value {
+                        "~2" | "-2" => -2,
+                        "~1" | "-1" => -1,
+                        "0" | "+0" | "-0" => 0,
+                        "1" | "+1" => 1,
+                        "2" | "+2" => 2,
+                        _ => return Err(GGRError::General("Wrong value for label. Only support for (-2,-1,0,1,2)".into())),
+                    };
+                    r.labels.entry(label.into()).or_insert(value);
+                }
+            }
+        };
+
+        if let Some(codereview) = y.value_of("code-review") {
+            let codereview = match codereview {
+                "~2" => -2,
+                "~1" => -1,
+                "0" => 0,
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(GGRError::General("Wrong code-review parameter".into())),
+            };
+            r.labels.entry("Code-Review".into()).or_insert(codereview);
+        };
+
+        r.labels
+    };
+
+    let mut comments: HashMap<String, Vec<entities::CommentInput>> = HashMap::new();
+    for comment in y.values_of("comment").into_iter().flatten() {
+        let (file, commentinput) = parse_comment(comment)?;
+        comments.entry(file).or_default().push(commentinput);
+    }
+
+    let reviewinput = entities::ReviewInput {
+        message: message.map(|m| m.to_string()),
+        labels: if labels.is_empty() { None } else { Some(labels) },
+        tag: y.value_of("tag").map(|t| t.to_string()),
+        notify: Changes::build_notify(y.value_of("notify"))?,
+        .. Default::default()
+    };
+
+    let mut gerrit = Gerrit::new(config.get_base_url());
+    let mut changes = gerrit.changes();
+
+    let features = if comments.is_empty() { vec!("CURRENT_REVISION") } else { vec!("CURRENT_REVISION", "CURRENT_FILES") };
+    if let Ok(changeinfos) = changes.query_changes(Some(vec!(&format!("topic:{}", topicname)[..])), Some(features)) {
+        /* overall review result for the commit */
+        let mut overall_review: HashMap<String /*label*/, (i8,i8) /* min/max */> = HashMap::new();
+
+        for ci in changeinfos {
+            debug!("{:?}", ci);
+            let files: Vec<String> = current_revision_files(&ci);
+            let (id, changeid, revision, subject) = (
+                ci.id.clone(),
+                ci.change_id.clone(),
+                ci.current_revision.unwrap_or_else(|| "".into()),
+                ci.subject
+            );
+
+            let changes = gerrit.changes();
+
+            if message.is_none() && reviewinput.labels.is_none() && comments.is_empty() {
+                // neither review or message is set, we retrieve review information
 
                 match changes.get_reviewers(&id) {
                     Ok(reviewerinfos) => {
@@ -819,9 +1986,29 @@ fn verify(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
                     }
                 };
             } else {
-                // message and/or review is set we push them to the gerrit server
-                match changes.set_review(&id, &revision, message, review.clone()) {
-                    Ok(reviewinfo) => println!("* {:5.5} {:20.20}, applied: {:?}", changeid, subject, reviewinfo.labels),
+                // message, review and/or comments are set we push them to the gerrit server
+                let mut reviewinput = reviewinput.clone();
+                let change_comments: HashMap<String, Vec<entities::CommentInput>> = comments.iter()
+                    .filter(|&(file, _)| files.contains(file))
+                    .map(|(file, c)| (file.clone(), c.clone()))
+                    .collect();
+                let comment_count = change_comments.values().map(|c| c.len()).sum::<usize>();
+                if !change_comments.is_empty() {
+                    reviewinput.comments = Some(change_comments);
+                } else if message.is_none() && reviewinput.labels.is_none() {
+                    println!("* {:5.5} {:20.20}, skipped: no commented file touched", changeid, subject);
+                    continue;
+                }
+
+                match changes.set_review(&id, &revision, &reviewinput) {
+                    Ok(reviewresult) => {
+                        println!("* {:5.5} {:20.20}, applied: {:?}, {} comments", changeid, subject, reviewresult.labels.unwrap_or_default(), comment_count);
+                        for (reviewer, result) in reviewresult.reviewers.unwrap_or_default() {
+                            if let Some(error) = result.error {
+                                println!("  reviewer {} not added: {}", reviewer, error);
+                            }
+                        }
+                    },
                     Err(err) => println!("* {:5.5} {:20.20}, not applied: {}", changeid, subject, err),
                 };
             }
@@ -840,13 +2027,40 @@ fn verify(y: &clap::ArgMatches, config: &config::Config) -> GGRResult<()> {
     Ok(())
 }
 
+/// parses an inline comment of format `FILE:LINE:MESSAGE`
+///
+/// LINE `0` creates a file comment.
+pub fn parse_comment(comment: &str) -> GGRResult<(String, entities::CommentInput)> {
+    let mut parts = comment.splitn(3, ':');
+    let file = parts.next().unwrap_or("").trim();
+    let line = parts.next().unwrap_or("").trim();
+    let message = parts.next().unwrap_or("").trim();
+
+    if file.is_empty() || message.is_empty() {
+        return Err(GGRError::General(format!("Wrong comment '{}', use 'FILE:LINE:MESSAGE'", comment)));
+    }
+
+    let line = match line.parse::<u64>() {
+        Ok(0) => None,
+        Ok(line) => Some(line),
+        Err(_) => return Err(GGRError::General(format!("Wrong line number in comment '{}'", comment))),
+    };
+
+    Ok((file.into(), entities::CommentInput {
+        line,
+        message: Some(message.into()),
+        .. Default::default()
+    }))
+}
+
 /// Convenient function to fetch topic `topicname` to branch `local_branch_name`.
 ///
 /// If branch exists and `force` is true, the branch is moving to new position.
-fn fetch_topic(gerrit: &mut Gerrit, topicname: &str, local_branch_name: &str, force: bool, tracking_branch_name: Option<&str>, closed: bool) -> GGRResult<()> {
+fn fetch_topic(gerrit: &mut Gerrit, topicname: &str, local_branch_name: &str, force: bool, tracking_branch_name: Option<&str>, closed: bool, transport: &config::FetchTransport) -> GGRResult<()> {
     trace!("fetch_topic: topicname:{} local_branch_name:{} force:{} tracking_branch_name:{:?} closed:{}",
            topicname, local_branch_name, force, tracking_branch_name, closed);
 
+    download_commands_hint(gerrit);
     let mut changes = gerrit.changes();
 
     let mut query_part = vec!(format!("topic:{}", topicname));
@@ -859,14 +2073,19 @@ fn fetch_topic(gerrit: &mut Gerrit, topicname: &str, local_branch_name: &str, fo
         println!("topic '{}' not found", topicname);
         return Ok(());
     }
-    fetch_changeinfos(&changeinfos, force, local_branch_name, tracking_branch_name)
+    fetch_changeinfos(&changeinfos, force, local_branch_name, tracking_branch_name, transport)
 }
 
 /// Convenient function to pull one or more `changeids`
 ///
 /// all ancestore commits are pulled from gerrit server too.
-pub fn fetch_changeinfos(changeinfos: &[entities::ChangeInfo], force: bool, local_branch_name: &str, tracking_branch_name: Option<&str>) -> GGRResult<()> {
+pub fn fetch_changeinfos(changeinfos: &[entities::ChangeInfo], force: bool, local_branch_name: &str, tracking_branch_name: Option<&str>, transport: &config::FetchTransport) -> GGRResult<()> {
     let project_tip = project_tip(changeinfos).unwrap();
+    let branchfetch = BranchFetch {
+        force,
+        tracking_branch_name,
+        transport,
+    };
 
     // try to fetch topic for main_repo and all submodules
     'next_ptip: for (p_name, p_tip) in project_tip {
@@ -874,7 +2093,7 @@ pub fn fetch_changeinfos(changeinfos: &[entities::ChangeInfo], force: bool, loca
         // check for root repository
         if let Ok(main_repo) = git2::Repository::open(".") {
             // check changes on root repository
-            match fetch_from_repo(&main_repo, changeinfos, force, local_branch_name, &p_name, &p_tip, tracking_branch_name) {
+            match fetch_from_repo(&main_repo, changeinfos, local_branch_name, &p_name, &p_tip, &branchfetch) {
                 Ok((true, x)) => {
                     println!("OK ({})", x);
                     continue;
@@ -895,7 +2114,7 @@ pub fn fetch_changeinfos(changeinfos: &[entities::ChangeInfo], force: bool, loca
             if let Ok(smodules) = main_repo.submodules() {
                 for smodule in smodules {
                     if let Ok(sub_repo) = smodule.open() {
-                        match fetch_from_repo(&sub_repo, changeinfos, force, local_branch_name, &p_name, &p_tip, tracking_branch_name) {
+                        match fetch_from_repo(&sub_repo, changeinfos, local_branch_name, &p_name, &p_tip, &branchfetch) {
                             Ok((true, _)) => {
                                 println!("OK");
                                 continue 'next_ptip;
@@ -923,6 +2142,16 @@ pub fn fetch_changeinfos(changeinfos: &[entities::ChangeInfo], force: bool, loca
     Ok(())
 }
 
+/// settings of a fetch into a local branch
+struct BranchFetch<'a> {
+    /// move an already existing branch to the fetched revision
+    force: bool,
+    /// upstream branch of the local branch
+    tracking_branch_name: Option<&'a str>,
+    /// scheme and credentials of the fetch
+    transport: &'a config::FetchTransport,
+}
+
 /// convenient function to pull a `project_tip` from a `repo`, if `basename(repo.url)` same as
 /// `project_name` is.
 ///
@@ -930,7 +2159,7 @@ pub fn fetch_changeinfos(changeinfos: &[entities::ChangeInfo], force: bool, loca
 ///
 /// returns `true` if something is pulled, and `false` if no pull was executed. The String object
 /// is a status message.
-fn fetch_from_repo(repo: &git2::Repository, ci: &[entities::ChangeInfo], force: bool, local_branch_name: &str, project_name: &str, project_tip: &str, tracking_branch_name: Option<&str>) -> GGRResult<(bool, String)> {
+fn fetch_from_repo(repo: &git2::Repository, ci: &[entities::ChangeInfo], local_branch_name: &str, project_name: &str, project_tip: &str, branchfetch: &BranchFetch) -> GGRResult<(bool, String)> {
     trace!("repo-path:{:?}, project_name:{}, project_tip:{}", repo.path().file_name(), project_name, project_tip);
     if repo.is_bare() {
         return Err(GGRError::General(format!("repository path '{:?}' is bare, we need a workdir", repo.path())));
@@ -957,14 +2186,12 @@ fn fetch_from_repo(repo: &git2::Repository, ci: &[entities::ChangeInfo], force:
                     for (ref revision, ref revisioninfo) in cirevisions {
                         if revision != current_revision { continue };
 
-                        for (fetchtype, fetchinfo) in &revisioninfo.fetch {
-                            if fetchtype.starts_with("http") {
-                                match do_fetch_from_repo(fetchinfo, local_branch_name, TagOrBranch::Branch, tracking_branch_name, force, false) {
-                                    Err(x) => return Err(x),
-                                    Ok(x) => {
-                                        return Ok((true, x))
-                                    },
-                                }
+                        if let Some(fetchinfo) = revision_fetchinfos(&entity.project, entity._number, revisioninfo, &branchfetch.transport.scheme).first() {
+                            match do_fetch_from_repo(fetchinfo, local_branch_name, TagOrBranch::Branch, branchfetch.tracking_branch_name, branchfetch.force, false, branchfetch.transport) {
+                                Err(x) => return Err(x),
+                                Ok(x) => {
+                                    return Ok((true, x))
+                                },
                             }
                         }
                     }
@@ -1026,6 +2253,72 @@ fn project_tip(changes: &[entities::ChangeInfo]) -> GGRResult<HashMap<String, St
     Ok(project_tip)
 }
 
+/// returns the current revision and his commit of a change
+///
+/// Only set if `CURRENT_REVISION` and `CURRENT_COMMIT` are requested for the change.
+fn current_revision_commit(change: &entities::ChangeInfo) -> Option<(&String, &entities::CommitInfo)> {
+    let current_revision = match change.current_revision {
+        Some(ref x) => x,
+        None => return None,
+    };
+
+    change.revisions.as_ref()
+        .and_then(|revisions| revisions.get(current_revision))
+        .and_then(|revisioninfo| revisioninfo.commit.as_ref())
+        .map(|commit| (current_revision, commit))
+}
+
+/// returns the file names of the current revision of `ci`
+///
+/// Needs the `CURRENT_REVISION` and `CURRENT_FILES` features in the query.
+fn current_revision_files(ci: &entities::ChangeInfo) -> Vec<String> {
+    ci.current_revision.as_ref()
+        .and_then(|rev| ci.revisions.as_ref().and_then(|revisions| revisions.get(rev)))
+        .and_then(|revinfo| revinfo.files.as_ref())
+        .map(|files| files.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// returns the `changes` in dependency order, a change is always behind his parent changes
+///
+/// The dependencies are computed from the parents of the current revision. For this
+/// `CURRENT_REVISION` and `CURRENT_COMMIT` have to be requested for the changes.
+pub fn sort_by_dependency(changes: &[entities::ChangeInfo]) -> Vec<&entities::ChangeInfo> {
+    let parents_of = |change: &entities::ChangeInfo| -> Vec<String> {
+        current_revision_commit(change)
+            .and_then(|(_, commit)| commit.parents.as_ref())
+            .map(|parents| parents.iter().map(|p| p.commit.clone()).collect())
+            .unwrap_or_default()
+    };
+
+    let mut sorted: Vec<&entities::ChangeInfo> = Vec::new();
+    let mut remaining: Vec<&entities::ChangeInfo> = changes.iter().collect();
+
+    while !remaining.is_empty() {
+        let count = remaining.len();
+
+        let mut i = 0;
+        while i < remaining.len() {
+            let parents = parents_of(remaining[i]);
+            let has_open_parent = remaining.iter()
+                .any(|other| other.current_revision.as_ref().is_some_and(|rev| parents.contains(rev)));
+
+            if has_open_parent {
+                i += 1;
+            } else {
+                sorted.push(remaining.remove(i));
+            }
+        }
+
+        // no progress, should never happen for commits
+        if remaining.len() == count {
+            sorted.append(&mut remaining);
+        }
+    }
+
+    sorted
+}
+
 pub fn entity_from_commit<'ci>(changes: &'ci [entities::ChangeInfo], commit: &str) -> GGRResult<&'ci entities::ChangeInfo> {
     for element in changes {
         if let Some(ref revisions) = element.revisions {
@@ -1151,3 +2444,121 @@ fn test_url_to_projectname() {
     assert_eq!(url_to_projectname("n/i/k/o/lause"), Some("lause"));
     assert_eq!(url_to_projectname(""), None);
 }
+
+#[test]
+fn test_sort_by_dependency() {
+    use serde_json;
+
+    let change = |number: u64, revision: &str, parent: &str| -> entities::ChangeInfo {
+        serde_json::from_value(json!({
+            "id": format!("p~master~I{}", number), "project": "p", "branch": "master",
+            "change_id": format!("I{}", number), "subject": "s", "status": "NEW",
+            "created": "", "updated": "", "insertions": 0, "deletions": 0, "_number": number,
+            "owner": {}, "current_revision": revision,
+            "revisions": { revision: {
+                "_number": 1, "fetch": {},
+                "commit": { "parents": [ { "commit": parent, "subject": "" } ] }
+            }}
+        })).unwrap()
+    };
+
+    let changes = vec!(change(3, "c", "b"), change(1, "a", "0"), change(2, "b", "a"), change(4, "x", "0"));
+    let sorted: Vec<u64> = sort_by_dependency(&changes).iter().map(|c| c._number).collect();
+
+    assert_eq!(sorted, vec!(1, 2, 4, 3));
+}
+
+#[test]
+fn test_parse_comment() {
+    let (file, comment) = parse_comment("src/main.rs:12:needs a test: really").unwrap();
+    assert_eq!(file, "src/main.rs");
+    assert_eq!(comment.line, Some(12));
+    assert_eq!(comment.message, Some("needs a test: really".into()));
+
+    let (_, comment) = parse_comment("README.md:0:typo").unwrap();
+    assert_eq!(comment.line, None);
+
+    assert!(parse_comment("README.md:x:typo").is_err());
+    assert!(parse_comment("README.md:1:").is_err());
+    assert!(parse_comment(":1:typo").is_err());
+}
+
+#[test]
+fn test_label_state() {
+    use serde_json;
+
+    let ci: entities::ChangeInfo = serde_json::from_value(json!({
+        "id": "p~master~I1", "project": "p", "branch": "master",
+        "change_id": "I1", "subject": "s", "status": "NEW",
+        "created": "", "updated": "", "insertions": 0, "deletions": 0, "_number": 1,
+        "owner": {},
+        "labels": {
+            "Code-Review": { "approved": { "_account_id": 1 } },
+            "Verified": {},
+            "Linter": { "optional": true },
+            "QA": { "rejected": { "_account_id": 2 }, "blocking": true }
+        }
+    })).unwrap();
+
+    assert_eq!(label_state(&ci), (vec!("Verified".to_string()), vec!("QA".to_string())));
+}
+
+#[test]
+fn test_pick_reviewer() {
+    use serde_json;
+
+    let suggestions: Vec<entities::SuggestedReviewerInfo> = serde_json::from_value(json!([
+        { "account": { "_account_id": 1, "name": "John Doe", "email": "john.doe@example.com", "username": "jdoe" }, "count": 1 },
+        { "account": { "_account_id": 2, "name": "John Dorian", "email": "jd@example.com", "username": "jd" }, "count": 1 },
+        { "group": { "id": "abc", "name": "johns" }, "count": 5 }
+    ])).unwrap();
+
+    let pick = |reviewer: &str, suggestions: &[entities::SuggestedReviewerInfo]| -> Option<String> {
+        pick_reviewer(reviewer, suggestions).ok().map(|sri| describe_suggestion(reviewer, sri).0)
+    };
+
+    assert_eq!(pick("jdoe", &suggestions), Some("1".into()));
+    assert_eq!(pick("JD@example.com", &suggestions), Some("2".into()));
+    assert_eq!(pick("johns", &suggestions), Some("abc".into()));
+    assert!(pick_reviewer("joh", &suggestions).is_err());
+    assert_eq!(pick("dor", &suggestions[1..2]), Some("2".into()));
+    assert!(pick_reviewer("nobody", &[]).is_err());
+}
+
+#[test]
+fn test_split_hashtags() {
+    let hashtags = vec!("+release-1".to_string(), "-release-0".into(), "hotfix".into(), "+".into(), "-".into());
+
+    assert_eq!(split_hashtags(&hashtags), (vec!("release-1", "hotfix"), vec!("release-0")));
+}
+
+#[test]
+fn test_revision_fetchinfos() {
+    use serde_json;
+
+    let revisioninfo: entities::RevisionInfo = serde_json::from_value(json!({"_number": 3})).unwrap();
+    let fetchinfos = revision_fetchinfos("tools/ggr", 4711, &revisioninfo, "http");
+    assert_eq!(fetchinfos.len(), 1);
+    assert_eq!(fetchinfos[0].url, "tools/ggr");
+    assert_eq!(fetchinfos[0].reference, "refs/changes/11/4711/3");
+
+    let revisioninfo: entities::RevisionInfo = serde_json::from_value(json!({"_number": 3, "fetch": {
+        "ssh": {"url": "ssh://host:29418/tools/ggr", "ref": "refs/changes/11/4711/3"},
+        "http": {"url": "https://host/tools/ggr", "ref": "refs/changes/11/4711/3"},
+    }})).unwrap();
+    let fetchinfos = revision_fetchinfos("tools/ggr", 4711, &revisioninfo, "http");
+    assert_eq!(fetchinfos.len(), 1);
+    assert_eq!(fetchinfos[0].url, "https://host/tools/ggr");
+
+    let fetchinfos = revision_fetchinfos("tools/ggr", 4711, &revisioninfo, "ssh");
+    assert_eq!(fetchinfos.len(), 1);
+    assert_eq!(fetchinfos[0].url, "ssh://host:29418/tools/ggr");
+
+    let revisioninfo: entities::RevisionInfo = serde_json::from_value(json!({"_number": 3, "fetch": {
+        "http": {"url": "https://host/tools/ggr", "ref": "refs/changes/11/4711/3"},
+    }})).unwrap();
+    let fetchinfos = revision_fetchinfos("tools/ggr", 4711, &revisioninfo, "ssh");
+    assert_eq!(fetchinfos.len(), 1);
+    assert_eq!(fetchinfos[0].url, "tools/ggr");
+    assert_eq!(fetchinfos[0].reference, "refs/changes/11/4711/3");
+}